@@ -26,7 +26,7 @@ fn main() {
             .set_elem_spacing_pixels(10)                   
         )
         .set_title("Button test!")
-    )
+    ).unwrap()
 }
 
 fn show_message_dialog(_: Button) {
@@ -0,0 +1,33 @@
+extern crate kiss_ui;
+
+use kiss_ui::prelude::*;
+
+use kiss_ui::canvas::{Canvas, DrawContext};
+
+fn main() {
+    kiss_ui::show_gui(|| {
+        Dialog::new(
+            Canvas::new()
+                .set_on_paint(paint_canvas)
+        )
+        .set_title("Canvas test!")
+        .set_size_pixels(320, 240)
+    }).unwrap();
+}
+
+fn paint_canvas((_, ctxt): (Canvas, DrawContext)) {
+    let (width, height) = ctxt.size();
+
+    ctxt.set_draw_color(255, 255, 255);
+    ctxt.fill_rect(0, 0, width, height);
+
+    ctxt.set_draw_color(0, 0, 255);
+    ctxt.fill_rect(20, 20, width / 2, height / 2);
+
+    ctxt.set_draw_color(255, 0, 0);
+    ctxt.draw_line(0, 0, width as i32, height as i32);
+    ctxt.draw_rect(10, 10, width - 20, height - 20);
+
+    ctxt.set_draw_color(0, 0, 0);
+    ctxt.draw_text("Hello, canvas!", 30, 30);
+}
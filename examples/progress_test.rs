@@ -1,12 +1,13 @@
 #[macro_use]
 extern crate kiss_ui;
 
-use kiss_ui::callback::OnShow;
+use kiss_ui::callback::OnMap;
 use kiss_ui::container::Vertical;
 use kiss_ui::dialog::Dialog;
 use kiss_ui::progress::ProgressBar;
 use kiss_ui::text::Label;
 use kiss_ui::timer::Timer;
+use kiss_ui::widget::Titled;
 
 fn main() {
     kiss_ui::show_gui(|| {
@@ -21,19 +22,19 @@ fn main() {
                     Label::new("Dashed:"),
                     dashed.clone(),
                     Label::new("Indefinite:"),
-                    ProgressBar::new().set_indefinite(true),
+                    ProgressBar::new().set_indeterminate(true),
                 ]
             )
         );        
 
         dialog
             .set_title("Progressbar Test")
-            .set_on_show(move |_| {
+            .set_on_map(move |_| {
                 let on_timer_interval = move |timer: Timer|{
                     regular.add_value(0.1);
                     dashed.add_value(0.1);
 
-                    if regular.get_value() == 1.0 {
+                    if regular.get_value() == Some(1.0) {
                         timer.stop();
                     }
                 };
@@ -45,6 +46,6 @@ fn main() {
             });
         
         dialog
-    });
+    }).unwrap();
 }
 
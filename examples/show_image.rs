@@ -5,6 +5,7 @@ use kiss_ui::container::Horizontal;
 use kiss_ui::dialog::Dialog;
 use kiss_ui::image::{Image, ImageContainer};
 use kiss_ui::text::Label;
+use kiss_ui::widget::Titled;
 
 fn main() {
     const WIDTH: u32 = 1920;
@@ -28,5 +29,5 @@ fn main() {
             )
         )
         .set_title("Image!")
-    });
+    }).unwrap();
 }
@@ -24,7 +24,7 @@ fn main() {
             )
         )
         .set_title("Textbox Test")
-    });
+    }).unwrap();
 }
 
 fn show_alert_message(clicked: Button) {
@@ -4,6 +4,7 @@ extern crate kiss_ui;
 use kiss_ui::container::Horizontal;
 use kiss_ui::dialog::Dialog;
 use kiss_ui::text::Label;
+use kiss_ui::widget::{Titled, Widget};
 
 fn main() {
     kiss_ui::show_gui(|| {
@@ -16,5 +17,5 @@ fn main() {
         )
         .set_title("Hello, world!")
         .set_size_pixels(640, 480)
-    });
+    }).unwrap();
 }
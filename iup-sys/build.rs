@@ -1,5 +1,13 @@
 fn main() {
     add_link("iup");
+
+    if cfg!(feature = "im") {
+        add_link("iupim");
+    }
+
+    if cfg!(feature = "controls") {
+        add_link("iupcontrols");
+    }
 }
 
 fn add_link(link: &str) {
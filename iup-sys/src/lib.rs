@@ -43,6 +43,7 @@ extern {
     pub fn IupMainLoopLevel() -> c_int;
     pub fn IupFlush();
     pub fn IupExitLoop();
+    pub fn IupPostMessage(ih: *mut Ihandle, s: *const c_char, i: c_int, d: c_double, p: *mut c_void);
 
     pub fn IupRecordInput(filename: *const c_char, mode: c_int) -> c_int;
     pub fn IupPlayInput(filename: *const c_char) -> c_int;
@@ -147,12 +148,12 @@ extern {
     pub fn IupPreviousField(ih: *mut Ihandle) -> *mut Ihandle;
     pub fn IupNextField(ih: *mut Ihandle) -> *mut Ihandle;
 
-    pub fn IupGetCallback(ih: *mut Ihandle, name: *const c_char) -> Icallback;
+    pub fn IupGetCallback(ih: *mut Ihandle, name: *const c_char) -> Option<Icallback>;
     pub fn IupSetCallback(ih: *mut Ihandle, name: *const c_char, func: Icallback) -> Icallback;
     pub fn IupSetCallbacks(ih: *mut Ihandle, name: *const c_char, func: Icallback, ...) -> *mut Ihandle;
 
-    pub fn IupGetFunction(name: *const c_char) -> Icallback;
-    pub fn IupSetFunction(name: *const c_char, func: Icallback) -> Icallback;
+    pub fn IupGetFunction(name: *const c_char) -> Option<Icallback>;
+    pub fn IupSetFunction(name: *const c_char, func: Option<Icallback>) -> Option<Icallback>;
 
     pub fn IupGetHandle(name: *const c_char) -> *mut Ihandle;
     pub fn IupSetHandle(name: *const c_char, ih: *mut Ihandle) -> *mut Ihandle;
@@ -230,8 +231,12 @@ extern {
     pub fn IupVal(_type: *const c_char) -> *mut Ihandle;
     pub fn IupTabs(child: *mut Ihandle, ...) -> *mut Ihandle;
     pub fn IupTabsv(children: *mut *mut Ihandle) -> *mut Ihandle;
+    pub fn IupFlatTabs(child: *mut Ihandle, ...) -> *mut Ihandle;
+    pub fn IupFlatTabsv(children: *mut *mut Ihandle) -> *mut Ihandle;
     pub fn IupTree() -> *mut Ihandle;
     pub fn IupLink(url: *const c_char, title: *const c_char) -> *mut Ihandle;
+    pub fn IupCalendar() -> *mut Ihandle;
+    pub fn IupDatePick() -> *mut Ihandle;
 
     /************************************************************************/
     /*                      Utilities                                       */
@@ -239,6 +244,8 @@ extern {
 
     /* IupImage utility */
     pub fn IupSaveImageAsText(ih: *mut Ihandle, file_name: *const c_char, format: *const c_char, name: *const c_char) -> c_int;
+    pub fn IupGetNativeHandleImage(handle: *mut c_void) -> *mut Ihandle;
+    pub fn IupGetImageNativeHandle(image: *mut Ihandle) -> *mut c_void;
 
     /* IupText and IupScintilla utilities */
     pub fn IupTextConvertLinColToPos(ih: *mut Ihandle, lin: c_int, col: c_int, pos: *mut c_int);
@@ -273,6 +280,26 @@ extern {
 
     pub fn IupLayoutDialog(dialog: *mut Ihandle) -> *mut Ihandle;
     pub fn IupElementPropertiesDialog(elem: *mut Ihandle) -> *mut Ihandle;
+
+    /************************************************************************/
+    /*                      Canvas Draw (iupdraw.h)                         */
+    /************************************************************************/
+    pub fn IupDrawBegin(ih: *mut Ihandle);
+    pub fn IupDrawEnd(ih: *mut Ihandle);
+    pub fn IupDrawSetClipRect(ih: *mut Ihandle, x1: c_int, y1: c_int, x2: c_int, y2: c_int);
+    pub fn IupDrawResetClip(ih: *mut Ihandle);
+    pub fn IupDrawParentBackground(ih: *mut Ihandle);
+    pub fn IupDrawLine(ih: *mut Ihandle, x1: c_int, y1: c_int, x2: c_int, y2: c_int);
+    pub fn IupDrawRectangle(ih: *mut Ihandle, x1: c_int, y1: c_int, x2: c_int, y2: c_int);
+    pub fn IupDrawArc(ih: *mut Ihandle, x1: c_int, y1: c_int, x2: c_int, y2: c_int, a1: c_double, a2: c_double);
+    pub fn IupDrawPolygon(ih: *mut Ihandle, points: *mut c_int, count: c_int);
+    pub fn IupDrawText(ih: *mut Ihandle, text: *const c_char, len: c_int, x: c_int, y: c_int, w: c_int, h: c_int);
+    pub fn IupDrawImage(ih: *mut Ihandle, name: *const c_char, x: c_int, y: c_int, w: c_int, h: c_int);
+    pub fn IupDrawSelectRect(ih: *mut Ihandle, x1: c_int, y1: c_int, x2: c_int, y2: c_int);
+    pub fn IupDrawFocusRect(ih: *mut Ihandle, x1: c_int, y1: c_int, x2: c_int, y2: c_int);
+    pub fn IupDrawGetSize(ih: *mut Ihandle, w: *mut c_int, h: *mut c_int);
+    pub fn IupDrawGetTextSize(ih: *mut Ihandle, text: *const c_char, len: c_int, w: *mut c_int, h: *mut c_int);
+    pub fn IupDrawGetImageInfo(name: *const c_char, w: *mut c_int, h: *mut c_int, bpp: *mut c_int);
 }
 
 /************************************************************************/
@@ -376,3 +403,18 @@ pub const IUP_GETPARAM_HELP: c_int   = -4;
 /************************************************************************/
 pub const IUP_RECBINARY: c_int = 0;
 pub const IUP_RECTEXT: c_int = 1;
+
+/************************************************************************/
+/*               Image Files (iupim.h, requires feature "im")           */
+/************************************************************************/
+#[cfg(feature = "im")]
+extern {
+    pub fn IupLoadImage(file_name: *const c_char) -> *mut Ihandle;
+    pub fn IupSaveImage(ih: *mut Ihandle, file_name: *const c_char, format: *const c_char) -> c_int;
+}
+
+#[cfg(feature = "controls")]
+extern {
+    pub fn IupControlsOpen() -> c_int;
+    pub fn IupMatrix(action: *const c_char) -> *mut Ihandle;
+}
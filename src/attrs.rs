@@ -1,6 +1,18 @@
 c_str_consts! {
     //Globals
     UTF8_MODE = "UTF8MODE",
+    MOD_KEY_STATE = "MODKEYSTATE",
+    IDLE_ACTION = "IDLE_ACTION",
+    MONITORS_INFO = "MONITORSINFO",
+    CURSOR_POS = "CURSORPOS",
+    SCREEN_DPI = "SCREENDPI",
+    DEFAULT_FONT = "DEFAULTFONT",
+    DLG_BG_COLOR = "DLGBGCOLOR",
+    DLG_FG_COLOR = "DLGFGCOLOR",
+    TXT_BG_COLOR = "TXTBGCOLOR",
+    TXT_FG_COLOR = "TXTFGCOLOR",
+    TXT_HL_COLOR = "TXTHLCOLOR",
+    DARK_MODE = "DARKMODE",
 
     // Basic widget attributes
     TITLE = "TITLE",
@@ -11,46 +23,281 @@ c_str_consts! {
 
     // Rendering attributes
     RASTERSIZE = "RASTERSIZE",
+    SIZE = "SIZE",
+    MIN_SIZE = "MINSIZE",
+    MAX_SIZE = "MAXSIZE",
     POSITION = "POSITION",
+    WID = "WID",
+    FONT = "FONT",
+    FONT_SIZE = "FONTSIZE",
+    FONT_STYLE = "FONTSTYLE",
+    BGCOLOR = "BGCOLOR",
+    FGCOLOR = "FGCOLOR",
+    TIP = "TIP",
+    TIP_DELAY = "TIPDELAY",
+    CURSOR = "CURSOR",
 
     // Layout attributes
+    ALIGNMENT = "ALIGNMENT",
+    EXPAND = "EXPAND",
+    CAN_FOCUS = "CANFOCUS",
     ALIGNMENT_VERT = "ALIGNMENTLIN",
     ALIGNMENT_HORI = "ALIGNMENTCOL",
     ORIENTATION = "ORIENTATION",
-    NUMDIV = "numdiv",
+    NUM_DIV = "NUMDIV",
+    GAP_LIN = "GAPLIN",
+    GAP_COL = "GAPCOL",
+    MIN_MAX = "MINMAX",
+    POS_X = "POSX",
+    POS_Y = "POSY",
+
+    // Tabs attributes
+    TAB_TITLE = "TABTITLE",
+    TAB_ORIENTATION = "TABORIENTATION",
+    TAB_TYPE = "TABTYPE",
+    VALUE_POS = "VALUEPOS",
+    SHOW_CLOSE = "SHOWCLOSE",
+    TAB_SHOW_CLOSE = "TABSHOWCLOSE",
 
     //Textbox attributes
     MULTILINE = "MULTILINE",
     VISIBLE_COLUMNS = "VISIBLECOLUMNS",
     VISIBLE_LINES = "VISIBLELINES",
+    APPEND = "APPEND",
+    LINE_COUNT = "LINECOUNT",
+    WORD_WRAP = "WORDWRAP",
+    READ_ONLY = "READONLY",
+    PASSWORD = "PASSWORD",
+    SCROLL_TO = "SCROLLTO",
+    CARET = "CARET",
+    CARET_POS = "CARETPOS",
+    SELECTION_POS = "SELECTIONPOS",
+    SELECTED_TEXT = "SELECTEDTEXT",
+    MASK = "MASK",
+    MASK_INT = "MASKINT",
+    MASK_FLOAT = "MASKFLOAT",
+    KISS_MASK_INT = "_KISS_MASKINT",
+    KISS_MASK_FLOAT = "_KISS_MASKFLOAT",
+
+    // List attributes
+    ITEM = "",
+    COUNT = "COUNT",
+    APPEND_ITEM = "APPENDITEM",
+    INSERT_ITEM = "INSERTITEM",
+    REMOVE_ITEM = "REMOVEITEM",
+    MULTIPLE = "MULTIPLE",
+
+    // Tree attributes
+    ADD_BRANCH = "ADDBRANCH",
+    ADD_LEAF = "ADDLEAF",
+    INSERT_BRANCH = "INSERTBRANCH",
+    INSERT_LEAF = "INSERTLEAF",
+    DEL_NODE = "DELNODE",
+    CHILD_COUNT = "CHILDCOUNT",
+    LAST = "LAST",
+    LAST_ADD_NODE = "LASTADDNODE",
+    KIND = "KIND",
+
+    // Spin attributes
+    SPIN = "SPIN",
+    SPIN_VALUE = "SPINVALUE",
+    SPIN_MIN = "SPINMIN",
+    SPIN_MAX = "SPINMAX",
+    SPIN_INC = "SPININC",
+    SPIN_WRAP = "SPINWRAP",
+    KISS_SPIN_LAST = "_KISS_SPINLAST",
+
+    // Slider attributes
+    STEP = "STEP",
+    PAGE_STEP = "PAGESTEP",
+    KISS_CONTINUOUS = "_KISS_CONTINUOUS",
+    KISS_DRAGGING = "_KISS_DRAGGING",
+    KISS_DRAG_PENDING = "_KISS_DRAGPENDING",
+
+    // Label attributes
+    SEPARATOR = "SEPARATOR",
+
+    // Toggle attributes
+    THREE_STATE = "3STATE",
+
+    // File dialog attributes
+    DIALOG_TYPE = "DIALOGTYPE",
+    FILTER = "FILTER",
+    MULTIPLE_FILES = "MULTIPLEFILES",
+    STATUS = "STATUS",
+
+    // Color dialog attributes
+    SHOW_ALPHA = "SHOWALPHA",
+    ALPHA = "ALPHA",
+
+    // Message dialog attributes
+    BUTTONS = "BUTTONS",
+    BUTTON_RESPONSE = "BUTTONRESPONSE",
+
+    // Canvas drawing attributes
+    DRAW_COLOR = "DRAWCOLOR",
+    DRAW_STYLE = "DRAWSTYLE",
+    DRAW_SIZE = "DRAWSIZE",
+
+    // Clipboard attributes
+    TEXT = "TEXT",
+    TEXT_AVAILABLE = "TEXTAVAILABLE",
+    IMAGE_AVAILABLE = "IMAGEAVAILABLE",
+    NATIVE_IMAGE = "NATIVEIMAGE",
+
+    // Image attributes
+    WIDTH = "WIDTH",
+    HEIGHT = "HEIGHT",
+
+    // Dialog attributes
+    ICON = "ICON",
+    RESIZE = "RESIZE",
+    MAX_BOX = "MAXBOX",
+    MIN_BOX = "MINBOX",
+    KISS_NO_MAX_BOX = "_KISS_NOMAXBOX",
+    SCREEN_X = "X",
+    SCREEN_Y = "Y",
+    PLACEMENT = "PLACEMENT",
+    MAXIMIZED = "MAXIMIZED",
+    MINIMIZED = "MINIMIZED",
+    KISS_ACCEL_FIRST = "_KISS_ACCELFIRST",
+
+    // Button attributes
+    FLAT = "FLAT",
+    IMAGE_POSITION = "IMAGEPOSITION",
+    IM_PRESS = "IMPRESS",
+    IM_INACTIVE = "IMINACTIVE",
+    KISS_IM_NORMAL = "_KISS_IMNORMAL",
+    KISS_IM_HOVER = "_KISS_IMHOVER",
+
+    // Link attributes
+    URL = "URL",
+
+    // Date attributes
+    KISS_DATE_MIN = "_KISS_DATEMIN",
+    KISS_DATE_MAX = "_KISS_DATEMAX",
+    WEEK_NUMBERS = "WEEKNUMBERS",
+
+    // Tray attributes
+    TRAY = "TRAY",
+    TRAY_IMAGE = "TRAYIMAGE",
+    TRAY_TIP = "TRAYTIP",
+    TRAY_TIP_BALLOON = "TRAYTIPBALLOON",
+    TRAY_TIP_BALLOON_TITLE = "TRAYTIPBALLOONTITLE",
+    TRAY_TIP_BALLOON_TITLE_ICON = "TRAYTIPBALLOONTITLEICON",
+
+    // Drag and drop attributes
+    DROP_FILES_TARGET = "DROPFILESTARGET",
+    DRAG_SOURCE = "DRAGSOURCE",
+    DRAG_TYPES = "DRAGTYPES",
+    DROP_TARGET = "DROPTARGET",
+    DROP_TYPES = "DROPTYPES",
+
+    // Menu attributes
+    MENU = "MENU",
+    AUTO_TOGGLE = "AUTOTOGGLE",
 
     // Progressbar attributes
     DASHED = "DASHED",
     MARQUEE = "MARQUEE",
+    KISS_VALUE = "_KISS_VALUE",
+    KISS_MIN = "_KISS_MIN",
+    KISS_MAX = "_KISS_MAX",
     MIN = "MIN",
     MAX = "MAX",
 
+    // Status bar attributes
+    KISS_STATUSBAR = "_KISS_STATUSBAR",
+
+    // Progress dialog attributes
+    DESCRIPTION = "DESCRIPTION",
+    TOTAL_COUNT = "TOTALCOUNT",
+    STATE = "STATE",
+
     //Timer attribute
     TIME = "TIME",
     RUN = "RUN",
+    KISS_PAUSED = "_KISS_PAUSED",
+    KISS_INTERVAL = "_KISS_INTERVAL",
+    KISS_REMAINING = "_KISS_REMAINING",
+    KISS_HOTKEY = "_KISS_HOTKEY",
 
     // Spacing between elements in a container
     GAP = "GAP",
+    MARGIN = "MARGIN",
 
     // Handles
     IMAGE = "IMAGE",
+    VALUE_HANDLE = "VALUE_HANDLE",
+    Z_ORDER = "ZORDER",
+    RESTORE = "RESTORE",
+    BORDER = "BORDER",
+    DIRECTION = "DIRECTION",
+    SCROLL_TO_CHILD_HANDLE = "SCROLLTOCHILD_HANDLE",
 
     //Callbacks
     ACTION = "ACTION",
+    LDESTROY_CB = "LDESTROY_CB",
     ACTION_CB = "ACTION_CB",
     VALUE_CHANGED_CB = "VALUECHANGED_CB",
+    VALUE_CHANGING_CB = "VALUECHANGING_CB",
     MAP_CB = "MAP_CB",
+    ENTER_WINDOW_CB = "ENTERWINDOW_CB",
+    LEAVE_WINDOW_CB = "LEAVEWINDOW_CB",
+    SHOW_CB = "SHOW_CB",
+    CLOSE_CB = "CLOSE_CB",
+    RESIZE_CB = "RESIZE_CB",
+    GET_FOCUS_CB = "GETFOCUS_CB",
+    KILL_FOCUS_CB = "KILLFOCUS_CB",
+    DROP_FILES_CB = "DROPFILES_CB",
+    DRAG_BEGIN_CB = "DRAGBEGIN_CB",
+    DRAG_DATA_SIZE_CB = "DRAGDATASIZE_CB",
+    DRAG_DATA_CB = "DRAGDATA_CB",
+    DROP_DATA_CB = "DROPDATA_CB",
+    POST_MESSAGE_CB = "POSTMESSAGE_CB",
+    TAB_CHANGE_POS_CB = "TABCHANGEPOS_CB",
+    DETACHED_CB = "DETACHED_CB",
+    TAB_CLOSE_CB = "TABCLOSE_CB",
+    K_ANY = "K_ANY",
+    BUTTON_CB = "BUTTON_CB",
+    DBLCLICK_CB = "DBLCLICK_CB",
+    MOTION_CB = "MOTION_CB",
+    TRAY_CLICK_CB = "TRAYCLICK_CB",
+    SELECTION_CB = "SELECTION_CB",
+    SPIN_CB = "SPIN_CB",
+    BRANCH_OPEN_CB = "BRANCHOPEN_CB",
+}
+
+// Matrix attributes, only used with the IUP controls library.
+#[cfg(feature = "controls")]
+c_str_consts! {
+    CELL = "",
+    NUM_LIN = "NUMLIN",
+    NUM_COL = "NUMCOL",
+    ADD_LIN = "ADDLIN",
+    ADD_COL = "ADDCOL",
+    REDRAW = "REDRAW",
+    VALUE_EDIT_CB = "VALUE_EDIT_CB",
 }
 
 pub mod values {
     c_str_consts! {
         YES = "YES",
         NO = "NO", 
+        ALL = "ALL",
+        ON = "ON",
+        OFF = "OFF",
+        NOTDEF = "NOTDEF",
+        NONE = "NONE",
+        HORIZONTAL = "HORIZONTAL",
+        VERTICAL = "VERTICAL",
+        NORMAL = "NORMAL",
+        MAXIMIZED = "MAXIMIZED",
+        MINIMIZED = "MINIMIZED",
+        TOP = "TOP",
+        BOTTOM = "BOTTOM",
+        OPEN = "OPEN",
+        CLOSE = "CLOSE",
     }
 
     pub fn bool_yes_no(_bool: bool) -> &'static str {
@@ -24,6 +24,32 @@ impl BaseWidget {
         })
     }
 
+    /// Remove the widget named by `name` from internal storage, returning it if it was found.
+    ///
+    /// The widget itself is not destroyed. Returns `None` if no widget by that name was stored.
+    ///
+    /// ##Panics
+    /// If called before `kiss_ui::show_gui()` is invoked or after it returns.
+    pub fn remove_stored<N: Borrow<str>>(name: N) -> Option<BaseWidget> {
+        assert_kiss_running!();
+
+        ::WIDGET_STORE.with(|store| {
+            store.borrow_mut().remove(name.borrow())
+        })
+    }
+
+    /// Check if a widget is stored under `name` in internal storage.
+    ///
+    /// ##Panics
+    /// If called before `kiss_ui::show_gui()` is invoked or after it returns.
+    pub fn is_stored<N: Borrow<str>>(name: N) -> bool {
+        assert_kiss_running!();
+
+        ::WIDGET_STORE.with(|store| {
+            store.borrow().contains_key(name.borrow())
+        })
+    }
+
     /// Attempt to downcast this `BaseWidget` to a more specialized widget type.
     ///
     /// This will return an error if the underlying widget class is different than the one 
@@ -31,6 +57,14 @@ impl BaseWidget {
     pub fn try_downcast<T>(self) -> Result<T, Self> where T: Downcast {
         T::try_downcast(self) 
     }
+
+    /// Attempt to cast this `BaseWidget` to a more specialized widget type.
+    ///
+    /// Returns `None` if the underlying widget class is different than the one it is being cast
+    /// to.
+    pub fn try_cast<W>(self) -> Option<W> where W: Widget {
+        self.try_downcast().ok()
+    }
 }
 
 impl_widget! { BaseWidget, "not an IUP widget!" }
@@ -56,7 +90,7 @@ pub trait Downcast: Widget {
 
     #[doc(hidden)]
     fn can_downcast(base: &BaseWidget) -> bool {
-        Self::target_classname().as_bytes() == base.classname().to_bytes()
+        Self::is_target_widget(base)
     }
 }
 
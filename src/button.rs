@@ -2,6 +2,15 @@
 
 use widget_prelude::*;
 
+use base::BaseWidget;
+use callback::Callback;
+use dialog::Dialog;
+use image::Image;
+use key::{Key, KeyMods};
+use utils::cstr::AsCStr;
+
+use iup_sys::CallbackReturn;
+
 use std::ptr;
 
 /// A button that can be clicked momentarily and invoke a callback when this happens.
@@ -17,14 +26,188 @@ impl Button {
     }
 
     /// Set the label of this button. Can be blank.
+    ///
+    /// Equivalent to `Titled::set_title()`.
     pub fn set_label<L: Into<String>>(self, label: L) -> Self {
-        self.set_str_attribute(::attrs::TITLE, label);
-        self        
+        self.set_title(label)
+    }
+
+    /// Set if this button hides its border until the mouse hovers over it, as in toolbars.
+    /// **Default:** `false`.
+    ///
+    /// Flat buttons still show a pressed state when clicked and invoke the same callbacks.
+    pub fn set_flat(self, flat: bool) -> Self {
+        self.set_bool_attribute(::attrs::FLAT, flat);
+        self
+    }
+
+    /// Set where the image of this button is placed relative to its label, if it has both.
+    /// **Default:** `ImagePosition::Left`.
+    ///
+    /// See `ImageContainer::set_image()`.
+    pub fn set_image_position(self, position: ImagePosition) -> Self {
+        self.set_const_str_attribute(::attrs::IMAGE_POSITION, position.as_cstr());
+        self
+    }
+
+    /// Set the images this button shows depending on its state: `normal` by default, `hover`
+    /// while the mouse is over it, `pressed` while it is held down, and `inactive` while it is
+    /// disabled.
+    ///
+    /// Without a `hover` or `pressed` image, `normal` is shown instead; without an `inactive`
+    /// image, a grayed out copy of `normal` is shown. This replaces any image set with
+    /// `ImageContainer::set_image()`.
+    ///
+    /// The button refers to the images without copying them, so they must not be destroyed
+    /// while it uses them.
+    pub fn set_state_images(
+        self, normal: Image, hover: Option<Image>, pressed: Option<Image>, inactive: Option<Image>
+    ) -> Self {
+        self.set_attr_handle(::attrs::IMAGE, normal);
+        self.set_attr_handle(::attrs::KISS_IM_NORMAL, normal);
+        self.set_opt_attr_handle(::attrs::KISS_IM_HOVER, hover);
+        self.set_opt_attr_handle(::attrs::IM_PRESS, pressed);
+        self.set_opt_attr_handle(::attrs::IM_INACTIVE, inactive);
+
+        self.set_callback(::attrs::ENTER_WINDOW_CB, hover_enter_callback);
+        self.set_callback(::attrs::LEAVE_WINDOW_CB, hover_leave_callback);
+
+        self
+    }
+
+    fn set_opt_attr_handle(self, name: &'static str, image: Option<Image>) {
+        match image {
+            Some(image) => self.set_attr_handle(name, image),
+            None => self.set_opt_str_attribute(name, None::<String>),
+        }
+    }
+
+    /// Activate this button when `key` is pressed with `mods` held down anywhere in its dialog, as
+    /// if it were clicked.
+    ///
+    /// This replaces any hotkey previously set on this button. The hotkey is ignored while the
+    /// button is hidden or disabled. If several buttons in one dialog share a hotkey, only the
+    /// first one is activated.
+    ///
+    /// ##Note
+    /// The dialog only sees key presses that the focused widget does not handle itself, e.g. in
+    /// an `OnKeyPress` callback.
+    pub fn set_hotkey(self, key: Key, mods: KeyMods) -> Self {
+        self.set_int_attribute(::attrs::KISS_HOTKEY, key.to_code(mods));
+        self
+    }
+
+    /// Remove the hotkey set by `set_hotkey()`, if any.
+    pub fn clear_hotkey(self) -> Self {
+        self.set_opt_str_attribute(::attrs::KISS_HOTKEY, None::<String>);
+        self
+    }
+
+    /// Set a callback to be invoked when the user double-clicks this button with the left mouse
+    /// button.
+    ///
+    /// IUP still reports both clicks to the `OnClick` callback, since it cannot know about the
+    /// second click when the first one is released. The second press is also reported to the
+    /// `OnMouseButton` callback, before this one.
+    pub fn set_on_double_click<Cb>(self, mut on_double_click: Cb) -> Self
+    where Cb: Callback<Self> {
+        ::callback::set_double_click_callback(self, Box::new(move |ptr| {
+            on_double_click.on_callback(unsafe { Button::from_ptr(ptr) })
+        }));
+
+        self
+    }
+}
+
+/// Where the image of a button is placed relative to its label.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ImagePosition {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl ImagePosition {
+    fn as_cstr(self) -> &'static str {
+        use self::ImagePosition::*;
+
+        match self {
+            Left => cstr!("LEFT"),
+            Right => cstr!("RIGHT"),
+            Top => cstr!("TOP"),
+            Bottom => cstr!("BOTTOM"),
+        }
     }
 }
 
 impl_widget! { Button, "button" }
 
+impl_on_map! { Button }
+
+impl Titled for Button {}
+
 impl_onclick! { Button }
 
+impl_on_action! { Button, ::attrs::ACTION }
+
+impl_on_key_press! { Button }
+
+impl_on_focus! { Button }
+
+impl_on_mouse_button! { Button }
+
 impl ::image::ImageContainer for Button {}
+
+extern "C" fn hover_enter_callback(element: IUPPtr) -> CallbackReturn {
+    swap_state_image(element, ::attrs::KISS_IM_HOVER)
+}
+
+extern "C" fn hover_leave_callback(element: IUPPtr) -> CallbackReturn {
+    swap_state_image(element, ::attrs::KISS_IM_NORMAL)
+}
+
+// Set by `Button::set_state_images()`; IUP buttons have no hover image of their own.
+fn swap_state_image(element: IUPPtr, name: &'static str) -> CallbackReturn {
+    let button = unsafe { Button::from_ptr(element) };
+
+    if let Some(image) = button.get_attr_handle(name) {
+        button.set_attr_handle(::attrs::IMAGE, image);
+    }
+
+    CallbackReturn::Default
+}
+
+/// Invoke the click callback of the button in `dialog` with the hotkey `code`, returning its
+/// result.
+///
+/// Returns `None` if no such button is enabled and visible.
+pub(crate) fn activate_hotkey(dialog: Dialog, code: i32) -> Option<CallbackReturn> {
+    let button = find_hotkey(dialog.to_base(), code)?;
+    let action = unsafe { ::iup_sys::IupGetCallback(button.ptr(), ::attrs::ACTION.as_cstr()) };
+
+    Some(action.map_or(CallbackReturn::Default, |action| action(button.ptr())))
+}
+
+fn find_hotkey(parent: BaseWidget, code: i32) -> Option<BaseWidget> {
+    let mut child = unsafe { BaseWidget::from_ptr_opt(::iup_sys::IupGetChild(parent.ptr(), 0)) };
+
+    while let Some(widget) = child {
+        let enabled = widget.get_str_attribute(::attrs::ACTIVE) != Some("NO");
+        let visible = widget.get_str_attribute(::attrs::VISIBLE) != Some("NO");
+
+        if enabled && visible {
+            if widget.get_int_attribute(::attrs::KISS_HOTKEY) == code {
+                return Some(widget);
+            }
+
+            if let Some(button) = find_hotkey(widget, code) {
+                return Some(button);
+            }
+        }
+
+        child = widget.get_sibling();
+    }
+
+    None
+}
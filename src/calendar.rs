@@ -0,0 +1,78 @@
+//! Always-visible month calendars.
+
+use callback::Callback;
+use datepick::{self, Date};
+use widget_prelude::*;
+
+/// A calendar showing a whole month, in which the user can select a day and move between
+/// months and years.
+///
+/// For a compact date field that only shows a calendar on demand, see `DatePicker`. The
+/// initially selected date is today.
+///
+/// For more info, see the [`IupCalendar`][iup-calendar] documentation.
+///
+/// [iup-calendar]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupcalendar.html
+pub struct Calendar(IUPPtr);
+
+impl Calendar {
+    /// Create a new calendar with today's date selected.
+    pub fn new() -> Calendar {
+        unsafe {
+            let ptr = ::iup_sys::IupCalendar();
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Select a date, showing its month.
+    ///
+    /// ##Panics
+    /// If the date does not exist, e.g. because `month` is `13` or `day` is `30` in February.
+    pub fn set_selected_date(self, year: i32, month: u32, day: u32) -> Self {
+        datepick::assert_valid_date(year, month, day);
+        self.set_str_attribute(::attrs::VALUE, datepick::format_date((year, month, day)));
+        self
+    }
+
+    /// Get the selected date as `(year, month, day)`, with `month` and `day` starting at `1`.
+    ///
+    /// This is read from the native calendar, so it always reflects the selection made by the
+    /// user. On some platforms, moving to another month also moves the selection to the same
+    /// day of that month.
+    pub fn get_selected_date(&self) -> Date {
+        self.get_str_attribute(::attrs::VALUE)
+            .and_then(datepick::parse_date)
+            .unwrap_or((1970, 1, 1))
+    }
+
+    /// Set if the number of each week in the year is shown next to it. **Default:** `false`.
+    pub fn set_week_numbers(self, week_numbers: bool) -> Self {
+        self.set_bool_attribute(::attrs::WEEK_NUMBERS, week_numbers);
+        self
+    }
+
+    /// Set a callback to be invoked when the selected date changes, either because the user
+    /// selected a day or, on some platforms, moved to another month, with the new `year`, `month`
+    /// and `day`.
+    pub fn set_on_date_selected<Cb>(self, on_date_selected: Cb) -> Self
+    where Cb: Callback<(Self, i32, u32, u32)> {
+        callback_impl! {
+            ::attrs::VALUE_CHANGED_CB, self, on_date_selected, Calendar,
+            () -> (Calendar, i32, u32, u32),
+            |calendar| {
+                let (year, month, day) = calendar.get_selected_date();
+                Some((calendar, year, month, day))
+            }
+        }
+
+        self
+    }
+}
+
+impl_widget! { Calendar, "calendar" }
+
+impl_on_map! { Calendar }
+
+impl_on_key_press! { Calendar }
+
+impl_on_focus! { Calendar }
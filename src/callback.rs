@@ -3,9 +3,15 @@
 use widget_prelude::*;
 
 use iup_sys::{Ihandle, CallbackReturn};
+use key::KeyMods;
+use libc::{c_char, c_int};
+use mouse::{MouseButton, MouseStatus};
+use utils::cstr::AsCStr;
 
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::hash::Hash;
+use std::thread::LocalKey;
 
 /// Set this within a callback to tell the framework if it should close or not.
 ///
@@ -13,13 +19,16 @@ use std::collections::HashMap;
 /// the dialog containing the widget on which the callback was invoked will be closed.
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub enum CallbackStatus {
-    //Ignore,
+    /// Suppress the default handling of the event that invoked the callback, e.g. to swallow a
+    /// keystroke. May not have an effect for all callbacks.
+    Ignore,
     /// The default `CallbackStatus`, does nothing when set.
     Default,
     /// If this is set within a callback, then when the callback returns the dialog containing the
     /// widget on which the callback was invoked will be closed.
     Close,
-    //Continue,
+    /// Pass the event on to the widget's parent, if the callback supports it.
+    Continue,
 }
 
 impl CallbackStatus {
@@ -32,9 +41,10 @@ impl CallbackStatus {
         use self::CallbackStatus::*;
 
         match self {
+            Ignore => CallbackReturn::Ignore,
             Close => CallbackReturn::Close,
             Default => CallbackReturn::Default,
-            // _ => unimplemented!(),
+            Continue => CallbackReturn::Continue,
         }
     }
 }
@@ -45,6 +55,10 @@ impl From<()> for CallbackStatus {
     }
 }
 
+/// A callback that can be set on a widget, implemented by all `FnMut` closures that take `Args`.
+///
+/// Each widget keeps its own callbacks, so closures can freely capture and mutate state. A
+/// callback is dropped when it is replaced or when its widget is destroyed.
 pub trait Callback<Args>: 'static {
     fn on_callback(&mut self, args: Args) -> CallbackStatus; 
 }
@@ -57,11 +71,106 @@ impl<Args, Out: Into<CallbackStatus>, F: 'static> Callback<Args> for F where F:
     }
 }
 
+/// The callbacks of one kind for every widget that has one.
+///
+/// A callback is taken out of its slot, leaving `None`, while it runs. This lets it set or
+/// remove callbacks, including its own, and destroy widgets without aliasing the map.
+///
+/// Callbacks are keyed by their widget, unless a widget can have several of one kind; e.g.
+/// `Dialog` keys its accelerators by the dialog and the key code.
+#[doc(hidden)]
+pub type CallbackMap<T, K = *mut Ihandle> = RefCell<HashMap<K, Option<Box<dyn Callback<T>>>>>;
+
+/// Set `callback` as the callback of `widget` in `callbacks`, dropping the previous one, if any.
+///
+/// The callback is dropped when the widget is destroyed.
 #[doc(hidden)]
-pub type CallbackMap<T> = RefCell<HashMap<*mut Ihandle, Box<Callback<T>>>>;
+pub fn store_callback<T: 'static, W: Widget>(
+    callbacks: &'static LocalKey<CallbackMap<T>>, widget: W, callback: Box<dyn Callback<T>>
+) {
+    let prev = callbacks.with(|callbacks|
+        callbacks.borrow_mut().insert(widget.ptr(), Some(callback))
+    );
+
+    if prev.is_none() {
+        on_destroy(widget, move |widget| {
+            let removed = callbacks.with(|callbacks| callbacks.borrow_mut().remove(&widget));
+            // Dropped outside the borrow, in case its captures destroy more widgets.
+            drop(removed);
+        });
+    }
+}
+
+/// Invoke the callback of `widget` in `callbacks` with `args`, if it has one.
+///
+/// Returns `CallbackStatus::Default` if it has none, or if its callback is already running.
+#[doc(hidden)]
+pub fn invoke_callback<T, K: Copy + Eq + Hash>(
+    callbacks: &'static LocalKey<CallbackMap<T, K>>, widget: K, args: T
+) -> CallbackStatus {
+    let callback = callbacks.with(|callbacks|
+        callbacks.borrow_mut().get_mut(&widget).and_then(Option::take)
+    );
+
+    let mut callback = match callback {
+        Some(callback) => callback,
+        None => return CallbackStatus::Default,
+    };
+
+    let status = callback.on_callback(args);
+
+    // Put it back, unless it was replaced or the widget was destroyed while it ran.
+    let unused = callbacks.with(|callbacks| match callbacks.borrow_mut().get_mut(&widget) {
+        Some(slot) if slot.is_none() => {
+            *slot = Some(callback);
+            None
+        },
+        _ => Some(callback),
+    });
+
+    drop(unused);
+
+    status
+}
+
+type DestroyHook = Box<dyn FnOnce(*mut Ihandle)>;
+
+thread_local! {
+    static DESTROY_HOOKS: RefCell<HashMap<*mut Ihandle, Vec<DestroyHook>>> =
+        RefCell::new(HashMap::new())
+}
 
+/// Invoke `hook` with the pointer of `widget` when it is destroyed, e.g. to drop the data
+/// associated with it.
+#[doc(hidden)]
+pub fn on_destroy<W, F>(widget: W, hook: F) where W: Widget, F: FnOnce(*mut Ihandle) + 'static {
+    DESTROY_HOOKS.with(|hooks|
+        hooks.borrow_mut()
+            .entry(widget.ptr())
+            .or_insert_with(|| {
+                // IUP reserves this callback for language bindings, so it is never taken by
+                // `DESTROY_CB` callbacks set elsewhere.
+                widget.set_callback(::attrs::LDESTROY_CB, destroy_callback);
+                Vec::new()
+            })
+            .push(Box::new(hook))
+    );
+}
+
+extern "C" fn destroy_callback(element: *mut Ihandle) -> CallbackReturn {
+    let hooks = DESTROY_HOOKS.with(|hooks| hooks.borrow_mut().remove(&element));
+
+    for hook in hooks.into_iter().flatten() {
+        hook(element);
+    }
+
+    CallbackReturn::Default
+}
+
+// Both forms take an optional `; invoke_action_callback` at the end, for the IUP callbacks that
+// `OnAction` callbacks are invoked from; see `impl_on_action!`.
 macro_rules! callback_impl {
-    ($cb_attr:expr, $base:expr, $callback:expr, $self_ty:ident) => (
+    ($cb_attr:expr, $base:expr, $callback:expr, $self_ty:ident $(; $chain:ident)*) => (
         { 
             thread_local!(
                 static CALLBACKS: ::callback::CallbackMap<$self_ty> = 
@@ -69,22 +178,60 @@ macro_rules! callback_impl {
             );
 
             extern fn extern_callback(element: *mut ::iup_sys::Ihandle) 
+            -> ::iup_sys::CallbackReturn {
+                let widget = unsafe { $self_ty::from_ptr(element) };
+                let status = ::callback::invoke_callback(&CALLBACKS, element, widget);
+                $(let status = ::callback::$chain(element, status);)*
+                status.to_cb_return()
+            }
+
+            ::callback::store_callback(&CALLBACKS, $base, Box::new($callback));
+            $base.set_callback($cb_attr, extern_callback);                
+        }
+    );
+    // For IUP callbacks that pass additional arguments after the element pointer.
+    //
+    // `$convert` maps the widget and the extra arguments to the value the user callback receives,
+    // or to `None` if the user callback should not be invoked for this event.
+    (
+        $cb_attr:expr, $base:expr, $callback:expr, $self_ty:ident,
+        ($($arg:ident: $arg_ty:ty),*) -> $args_ty:ty, |$widget:ident| $convert:expr
+        $(; $chain:ident)*
+    ) => (
+        {
+            thread_local!(
+                static CALLBACKS: ::callback::CallbackMap<$args_ty> =
+                    ::std::cell::RefCell::new(::std::collections::HashMap::new())
+            );
+
+            extern "C" fn extern_callback(element: *mut ::iup_sys::Ihandle $(, $arg: $arg_ty)*)
             -> ::iup_sys::CallbackReturn {
                 use ::callback::CallbackStatus;
 
-                let widget = unsafe { $self_ty::from_ptr(element) };
+                let $widget = unsafe { $self_ty::from_ptr(element) };
+                let args: Option<$args_ty> = $convert;
+
+                let status = match args {
+                    Some(args) => ::callback::invoke_callback(&CALLBACKS, element, args),
+                    None => CallbackStatus::Default,
+                };
 
-                CALLBACKS.with(|callbacks| 
-                    callbacks.borrow_mut()
-                        .get_mut(&widget.ptr())
-                        .map(|cb| cb.on_callback(widget))
-                ).unwrap_or(CallbackStatus::Default).to_cb_return()
+                $(let status = ::callback::$chain(element, status);)*
+                status.to_cb_return()
             }
 
-            CALLBACKS.with(|callbacks| 
-                callbacks.borrow_mut().insert($base.ptr(), Box::new($callback))
-            );
-            $base.set_callback($cb_attr, extern_callback);                
+            ::callback::store_callback(&CALLBACKS, $base, Box::new($callback));
+
+            // A no-op for IUP callbacks without extra arguments.
+            #[allow(clippy::useless_transmute)]
+            let extern_callback = unsafe {
+                ::std::mem::transmute::<
+                    extern "C" fn(*mut ::iup_sys::Ihandle $(, $arg_ty)*) -> ::iup_sys::CallbackReturn,
+                    ::iup_sys::Icallback
+                >(extern_callback)
+            };
+
+            $base.set_callback($cb_attr, extern_callback);
         }
     )
 }
@@ -98,7 +245,95 @@ macro_rules! impl_onclick {
     ($self_ty:ident) => (
         impl $crate::callback::OnClick for $self_ty {
             fn set_onclick<Cb>(self, on_click: Cb) -> Self where Cb: ::callback::Callback<Self> {
-                callback_impl! { $crate::attrs::ACTION, self, on_click, $self_ty }
+                callback_impl! {
+                    $crate::attrs::ACTION, self, on_click, $self_ty; invoke_action_callback
+                }
+                self
+            }
+        }
+    )
+}
+
+/// A trait describing a widget that can be activated by the user, and can notify client code
+/// when this occurs.
+///
+/// This is the same for every widget that implements it, whatever its native event: clicking a
+/// `Button` or `MenuItem`, toggling a `Toggle`, or double-clicking an item of a `List`.
+pub trait OnAction: Widget {
+    /// Set a callback to be invoked when this widget is activated.
+    ///
+    /// This does not replace the widget's own callback for that event, like
+    /// `OnClick::set_onclick()` or `Toggle::set_on_toggled()`; if both are set, this one is
+    /// invoked after it.
+    fn set_on_action<Cb>(self, on_action: Cb) -> Self where Cb: Callback<Self>;
+}
+
+thread_local! {
+    static ACTION_CALLBACKS: CallbackMap<*mut Ihandle> = RefCell::new(HashMap::new());
+}
+
+/// Invoke the `OnAction` callback of `element`, if any, after its own callback for the same
+/// event returned `status`.
+///
+/// Returns `status`, unless it is `CallbackStatus::Default`.
+#[doc(hidden)]
+pub fn invoke_action_callback(element: *mut Ihandle, status: CallbackStatus) -> CallbackStatus {
+    let action = invoke_callback(&ACTION_CALLBACKS, element, element);
+    if status == CallbackStatus::Default { action } else { status }
+}
+
+/// Set the `OnAction` callback of `widget`, and `extern_callback` as its IUP callback `cb_attr`,
+/// unless the widget's own callback for that event already invokes it.
+#[doc(hidden)]
+pub fn set_action_callback<W: Widget>(
+    widget: W, cb_attr: &'static str, extern_callback: ::iup_sys::Icallback,
+    callback: Box<dyn Callback<*mut Ihandle>>
+) {
+    store_callback(&ACTION_CALLBACKS, widget, callback);
+
+    let existing = unsafe { ::iup_sys::IupGetCallback(widget.ptr(), cb_attr.as_cstr()) };
+
+    if existing.is_none() {
+        widget.set_callback(cb_attr, extern_callback);
+    }
+}
+
+// `impl_on_action! { Type, ATTR }` for IUP callbacks taking only the element, or
+// `impl_on_action! { Type, ATTR, (arg: ty, ...) }` for ones with extra arguments, which are
+// ignored.
+//
+// The widget's own callback for `ATTR` must be set with `callback_impl!` and end with
+// `; invoke_action_callback`, so setting either one keeps the other.
+macro_rules! impl_on_action {
+    ($self_ty:ident, $cb_attr:expr) => (
+        impl_on_action! { $self_ty, $cb_attr, () }
+    );
+    ($self_ty:ident, $cb_attr:expr, ($($arg:ident: $arg_ty:ty),*)) => (
+        impl ::callback::OnAction for $self_ty {
+            fn set_on_action<Cb>(self, mut on_action: Cb) -> Self
+            where Cb: ::callback::Callback<Self> {
+                extern "C" fn extern_callback(
+                    element: *mut ::iup_sys::Ihandle $(, $arg: $arg_ty)*
+                ) -> ::iup_sys::CallbackReturn {
+                    let status = ::callback::CallbackStatus::Default;
+                    ::callback::invoke_action_callback(element, status).to_cb_return()
+                }
+
+                // A no-op for IUP callbacks without extra arguments.
+                #[allow(clippy::useless_transmute)]
+                let extern_callback = unsafe {
+                    ::std::mem::transmute::<
+                        extern "C" fn(*mut ::iup_sys::Ihandle $(, $arg_ty)*)
+                        -> ::iup_sys::CallbackReturn,
+                        ::iup_sys::Icallback
+                    >(extern_callback)
+                };
+
+                ::callback::set_action_callback(self, $cb_attr, extern_callback, Box::new(
+                    move |ptr: *mut ::iup_sys::Ihandle| {
+                        on_action.on_callback(unsafe { $self_ty::from_ptr(ptr) })
+                    }
+                ));
                 self
             }
         }
@@ -122,8 +357,36 @@ macro_rules! impl_on_value_change {
     )
 }
 
+/// A trait describing a widget that gets a native handle when it is first shown, and can notify
+/// client code when this occurs.
+pub trait OnMap: Widget {
+    /// Set a callback to be invoked when the native handle of this widget has been created, right
+    /// before it is first shown, e.g. to initialize a canvas with its final size.
+    ///
+    /// This happens once per native handle, so it only fires again if the widget is detached
+    /// from its dialog and re-added, or its dialog is destroyed and recreated.
+    fn set_on_map<Cb>(self, on_map: Cb) -> Self where Cb: Callback<Self>;
+}
+
+macro_rules! impl_on_map {
+    ($self_ty:ident) => (
+        impl ::callback::OnMap for $self_ty {
+            fn set_on_map<Cb>(self, on_map: Cb) -> Self where Cb: ::callback::Callback<Self> {
+                callback_impl! { ::attrs::MAP_CB, self, on_map, $self_ty }
+                self
+            }
+        }
+    )
+}
+
 /// A trait describing a widget that can be shown, and can notify client code when this occurs.
+///
+/// Superseded by `OnMap`, which this is an alias of, and `OnShowState`.
 pub trait OnShow: Widget {
+    /// Set a callback to be invoked when the native handle of this widget has been created, like
+    /// `OnMap::set_on_map()`.
+    #[deprecated(note = "use `OnMap::set_on_map()`, or `OnShowState::set_on_show_state()` to be \
+                         notified whenever the window is shown, hidden, maximized or minimized")]
     fn set_on_show<Cb>(self, on_show: Cb) -> Self where Cb: Callback<Self>;
 }
 
@@ -131,7 +394,266 @@ macro_rules! impl_on_show {
     ($self_ty:ident) => (
         impl ::callback::OnShow for $self_ty {
             fn set_on_show<Cb>(self, on_show: Cb) -> Self where Cb: ::callback::Callback<Self> {
-                callback_impl! { ::attrs::MAP_CB, self, on_show, $self_ty }
+                ::callback::OnMap::set_on_map(self, on_show)
+            }
+        }
+    )
+}
+
+/// A trait describing a window that can be shown, hidden, maximized and minimized, and can
+/// notify client code when this occurs.
+pub trait OnShowState: Widget {
+    /// Set a callback to be invoked when this window's visibility or placement changes, either
+    /// programmatically or by the user, with the new state.
+    fn set_on_show_state<Cb>(self, on_show_state: Cb) -> Self
+    where Cb: Callback<(Self, ShowState)>;
+}
+
+macro_rules! impl_on_show_state {
+    ($self_ty:ident) => (
+        impl ::callback::OnShowState for $self_ty {
+            fn set_on_show_state<Cb>(self, on_show_state: Cb) -> Self
+            where Cb: ::callback::Callback<(Self, ::callback::ShowState)> {
+                callback_impl! {
+                    ::attrs::SHOW_CB, self, on_show_state, $self_ty,
+                    (state: ::libc::c_int) -> ($self_ty, ::callback::ShowState),
+                    |widget| ::callback::ShowState::from_state(state).map(|state| (widget, state))
+                }
+                self
+            }
+        }
+    )
+}
+
+/// A change of a window's visibility or placement, as passed to
+/// `OnShowState::set_on_show_state()`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ShowState {
+    /// The window was shown.
+    Shown,
+    /// The window was hidden.
+    Hidden,
+    /// The window was maximized.
+    Maximized,
+    /// The window was minimized.
+    Minimized,
+    /// The window was restored from being maximized or minimized.
+    Restored,
+}
+
+impl ShowState {
+    #[doc(hidden)]
+    pub fn from_state(state: ::libc::c_int) -> Option<ShowState> {
+        match state {
+            ::iup_sys::IUP_SHOW => Some(ShowState::Shown),
+            ::iup_sys::IUP_HIDE => Some(ShowState::Hidden),
+            ::iup_sys::IUP_MAXIMIZE => Some(ShowState::Maximized),
+            ::iup_sys::IUP_MINIMIZE => Some(ShowState::Minimized),
+            ::iup_sys::IUP_RESTORE => Some(ShowState::Restored),
+            _ => None,
+        }
+    }
+}
+
+/// A trait describing a widget that can receive keyboard input, and can notify client code when
+/// a key is pressed while it has the focus.
+pub trait OnKeyPress: Widget {
+    /// Set a callback to be invoked when a key is pressed while this widget has the focus,
+    /// with the key and the modifiers held down.
+    ///
+    /// Return `CallbackStatus::Ignore` to swallow the keystroke, or `CallbackStatus::Continue` to
+    /// pass it on to the parent widget.
+    fn set_on_key_press<Cb>(self, on_key_press: Cb) -> Self
+    where Cb: Callback<(Self, ::key::Key, ::key::KeyMods)>;
+}
+
+macro_rules! impl_on_key_press {
+    ($self_ty:ident) => (
+        impl ::callback::OnKeyPress for $self_ty {
+            fn set_on_key_press<Cb>(self, on_key_press: Cb) -> Self
+            where Cb: ::callback::Callback<(Self, ::key::Key, ::key::KeyMods)> {
+                callback_impl! {
+                    ::attrs::K_ANY, self, on_key_press, $self_ty,
+                    (code: ::libc::c_int) -> ($self_ty, ::key::Key, ::key::KeyMods),
+                    |widget| Some((widget, ::key::Key::from_code(code), ::key::KeyMods::from_code(code)))
+                }
+                self
+            }
+        }
+    )
+}
+
+/// A trait describing a widget that can notify client code when a mouse button is pressed or
+/// released over it.
+pub trait OnMouseButton: Widget {
+    /// Set a callback to be invoked when a mouse button is pressed (`true`) or released (`false`)
+    /// over this widget, with the position of the cursor relative to the widget's top-left corner
+    /// and the modifiers held down.
+    fn set_on_mouse_button<Cb>(self, on_mouse_button: Cb) -> Self
+    where Cb: Callback<(Self, ::mouse::MouseButton, bool, i32, i32, ::key::KeyMods)>;
+}
+
+macro_rules! impl_on_mouse_button {
+    ($self_ty:ident) => (
+        impl ::callback::OnMouseButton for $self_ty {
+            fn set_on_mouse_button<Cb>(self, mut on_mouse_button: Cb) -> Self
+            where Cb: ::callback::Callback<(Self, ::mouse::MouseButton, bool, i32, i32, ::key::KeyMods)> {
+                ::callback::set_mouse_button_callback(self, Box::new(
+                    move |(ptr, button, pressed, x, y, mods): ::callback::MouseButtonArgs| {
+                        let widget = unsafe { $self_ty::from_ptr(ptr) };
+                        on_mouse_button.on_callback((widget, button, pressed, x, y, mods))
+                    }
+                ));
+                self
+            }
+        }
+    )
+}
+
+#[doc(hidden)]
+pub type MouseButtonArgs = (*mut Ihandle, MouseButton, bool, i32, i32, KeyMods);
+
+thread_local! {
+    static MOUSE_BUTTON_CALLBACKS: CallbackMap<MouseButtonArgs> = RefCell::new(HashMap::new());
+    static DOUBLE_CLICK_CALLBACKS: CallbackMap<*mut Ihandle> = RefCell::new(HashMap::new());
+}
+
+/// Set the `OnMouseButton` callback of `widget`, invoked by `button_callback()`.
+pub(crate) fn set_mouse_button_callback<W: Widget>(
+    widget: W, callback: Box<dyn Callback<MouseButtonArgs>>
+) {
+    store_callback(&MOUSE_BUTTON_CALLBACKS, widget, callback);
+    set_button_callback(widget);
+}
+
+/// Set a callback of `widget` for double-clicks with the left mouse button, invoked by
+/// `button_callback()`.
+pub(crate) fn set_double_click_callback<W: Widget>(
+    widget: W, callback: Box<dyn Callback<*mut Ihandle>>
+) {
+    store_callback(&DOUBLE_CLICK_CALLBACKS, widget, callback);
+    set_button_callback(widget);
+}
+
+/// Make `button_callback()` the `BUTTON_CB` of `widget`.
+///
+/// Mouse button callbacks, double-click callbacks and context menus all need this IUP callback,
+/// so it dispatches to each of them instead of any one replacing the others.
+pub(crate) fn set_button_callback<W: Widget>(widget: W) {
+    let callback = unsafe {
+        ::std::mem::transmute::<
+            extern "C" fn(*mut Ihandle, c_int, c_int, c_int, c_int, *mut c_char) -> CallbackReturn,
+            ::iup_sys::Icallback
+        >(button_callback)
+    };
+
+    widget.set_callback(::attrs::BUTTON_CB, callback);
+}
+
+extern "C" fn button_callback(
+    element: *mut Ihandle, button: c_int, pressed: c_int, x: c_int, y: c_int, status: *mut c_char
+) -> CallbackReturn {
+    let status = unsafe { MouseStatus::from_ptr(status) };
+    let (mouse_button, pressed) = (MouseButton::from_code(button), pressed != 0);
+
+    let mut result = match mouse_button {
+        Some(mouse_button) => {
+            let args = (element, mouse_button, pressed, x, y, status.mods);
+            invoke_callback(&MOUSE_BUTTON_CALLBACKS, element, args)
+        },
+        None => CallbackStatus::Default,
+    };
+
+    // The press that completes a double-click has already been reported above.
+    if mouse_button == Some(MouseButton::Left) && pressed && status.double_click {
+        let double_click = invoke_callback(&DOUBLE_CLICK_CALLBACKS, element, element);
+
+        if result == CallbackStatus::Default {
+            result = double_click;
+        }
+    }
+
+    ::menu::show_context_menu(element, button, pressed);
+
+    result.to_cb_return()
+}
+
+/// A trait describing a widget that can notify client code when the mouse moves over it.
+pub trait OnMouseMotion: Widget {
+    /// Set a callback to be invoked when the mouse moves over this widget, with the position of
+    /// the cursor relative to the widget's top-left corner and the modifiers held down.
+    fn set_on_mouse_motion<Cb>(self, on_mouse_motion: Cb) -> Self
+    where Cb: Callback<(Self, i32, i32, ::key::KeyMods)>;
+}
+
+macro_rules! impl_on_mouse_motion {
+    ($self_ty:ident) => (
+        impl ::callback::OnMouseMotion for $self_ty {
+            fn set_on_mouse_motion<Cb>(self, on_mouse_motion: Cb) -> Self
+            where Cb: ::callback::Callback<(Self, i32, i32, ::key::KeyMods)> {
+                callback_impl! {
+                    ::attrs::MOTION_CB, self, on_mouse_motion, $self_ty,
+                    (x: ::libc::c_int, y: ::libc::c_int, status: *mut ::libc::c_char)
+                    -> ($self_ty, i32, i32, ::key::KeyMods),
+                    |widget| {
+                        let status = unsafe { ::mouse::MouseStatus::from_ptr(status) };
+                        Some((widget, x, y, status.mods))
+                    }
+                }
+                self
+            }
+        }
+    )
+}
+
+/// A trait describing a widget that can be resized, and can notify client code when this occurs.
+pub trait OnResize: Widget {
+    /// Set a callback to be invoked when this widget is resized, with the new width and height of
+    /// its client area in pixels, i.e. excluding the title bar, borders and scrollbars.
+    ///
+    /// It is also invoked when the widget is first shown.
+    fn set_on_resize<Cb>(self, on_resize: Cb) -> Self where Cb: Callback<(Self, u32, u32)>;
+}
+
+macro_rules! impl_on_resize {
+    ($self_ty:ident) => (
+        impl ::callback::OnResize for $self_ty {
+            fn set_on_resize<Cb>(self, on_resize: Cb) -> Self
+            where Cb: ::callback::Callback<(Self, u32, u32)> {
+                callback_impl! {
+                    ::attrs::RESIZE_CB, self, on_resize, $self_ty,
+                    (width: ::libc::c_int, height: ::libc::c_int) -> ($self_ty, u32, u32),
+                    |widget| Some((widget, width.max(0) as u32, height.max(0) as u32))
+                }
+                self
+            }
+        }
+    )
+}
+
+/// A trait describing a widget that can receive the keyboard focus, and can notify client code
+/// when it gains or loses it.
+pub trait OnFocus: Widget {
+    /// Set a callback to be invoked when this widget receives the keyboard focus.
+    fn set_on_focus_gained<Cb>(self, on_focus_gained: Cb) -> Self where Cb: Callback<Self>;
+
+    /// Set a callback to be invoked when this widget loses the keyboard focus, e.g. to validate
+    /// its contents. The focus can be moved back with `Widget::set_focus()`.
+    fn set_on_focus_lost<Cb>(self, on_focus_lost: Cb) -> Self where Cb: Callback<Self>;
+}
+
+macro_rules! impl_on_focus {
+    ($self_ty:ident) => (
+        impl ::callback::OnFocus for $self_ty {
+            fn set_on_focus_gained<Cb>(self, on_focus_gained: Cb) -> Self
+            where Cb: ::callback::Callback<Self> {
+                callback_impl! { ::attrs::GET_FOCUS_CB, self, on_focus_gained, $self_ty }
+                self
+            }
+
+            fn set_on_focus_lost<Cb>(self, on_focus_lost: Cb) -> Self
+            where Cb: ::callback::Callback<Self> {
+                callback_impl! { ::attrs::KILL_FOCUS_CB, self, on_focus_lost, $self_ty }
                 self
             }
         }
@@ -0,0 +1,159 @@
+//! A widget for custom drawing.
+
+use widget_prelude::*;
+
+use ::callback::Callback;
+use ::widget::Color;
+
+use std::ffi::CString;
+use std::ptr;
+
+/// A widget with a blank drawing surface, which client code can render to in a paint callback.
+///
+/// For more info, see the [`IupCanvas`][iup-canvas] documentation.
+///
+/// [iup-canvas]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupcanvas.html
+pub struct Canvas(IUPPtr);
+
+impl Canvas {
+    /// Create a new, blank canvas.
+    pub fn new() -> Canvas {
+        unsafe {
+            let ptr = ::iup_sys::IupCanvas(ptr::null());
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Set a callback to be invoked whenever the canvas needs to be redrawn.
+    ///
+    /// The callback receives a `DrawContext` with which it can render the canvas contents.
+    pub fn set_on_paint<Cb>(self, on_paint: Cb) -> Self where Cb: Callback<(Self, DrawContext)> {
+        callback_impl! {
+            ::attrs::ACTION, self, on_paint, Canvas,
+            (_posx: ::libc::c_float, _posy: ::libc::c_float) -> (Canvas, DrawContext),
+            |canvas| Some((canvas, DrawContext::begin(canvas)))
+        }
+
+        self
+    }
+
+    /// Get the size of the drawing surface, in pixels, as `DrawContext::size()` returns it within
+    /// the paint callback.
+    ///
+    /// Unlike `get_size_pixels()`, this excludes borders and scrollbars. It is measured in the
+    /// same pixels as `DrawContext` coordinates, so it matches what is drawn even when the
+    /// display is scaled for high DPI.
+    ///
+    /// The size is available from the `OnMap` callback on, and is already updated when the
+    /// `OnResize` callback is invoked. Returns `(0, 0)` before the canvas is shown.
+    pub fn get_drawable_size(&self) -> (u32, u32) {
+        if self.get_str_attribute(::attrs::WID).is_none() {
+            return (0, 0);
+        }
+
+        let (width, height) = self.get_int2_attribute(::attrs::DRAW_SIZE);
+        (width.max(0) as u32, height.max(0) as u32)
+    }
+
+    /// Force the canvas to be redrawn immediately, invoking the paint callback.
+    pub fn redraw(self) -> Self {
+        unsafe { ::iup_sys::IupRedraw(self.ptr(), 0); }
+        self
+    }
+}
+
+impl_widget! { Canvas, "canvas" }
+
+impl_on_map! { Canvas }
+
+impl_on_key_press! { Canvas }
+
+impl_on_mouse_button! { Canvas }
+
+impl_on_mouse_motion! { Canvas }
+
+impl_on_resize! { Canvas }
+
+/// A set of drawing primitives for rendering to a `Canvas`, available within its paint callback.
+///
+/// Coordinates are in pixels, relative to the top-left corner of the canvas.
+///
+/// ##Note: Double Buffering
+/// IUP always draws to an off-screen buffer, which is copied to the canvas all at once when
+/// this is dropped at the end of the paint callback, so animations don't flicker and partially
+/// drawn frames are never shown. The buffer is allocated for each frame at the current size of
+/// the canvas, so it always matches it after a resize. There is no single-buffered mode.
+pub struct DrawContext {
+    canvas: Canvas,
+}
+
+impl DrawContext {
+    fn begin(canvas: Canvas) -> DrawContext {
+        unsafe { ::iup_sys::IupDrawBegin(canvas.ptr()); }
+        DrawContext { canvas }
+    }
+
+    /// Get the canvas being drawn to.
+    pub fn canvas(&self) -> Canvas {
+        self.canvas
+    }
+
+    /// Get the size of the drawing surface, in pixels.
+    pub fn size(&self) -> (u32, u32) {
+        let mut width = 0;
+        let mut height = 0;
+
+        unsafe { ::iup_sys::IupDrawGetSize(self.canvas.ptr(), &mut width, &mut height); }
+
+        (width as u32, height as u32)
+    }
+
+    /// Set the color used by all following drawing operations.
+    pub fn set_draw_color(&self, r: u8, g: u8, b: u8) {
+        self.canvas.set_str_attribute(::attrs::DRAW_COLOR, Color::new(r, g, b).to_string());
+    }
+
+    /// Fill the whole drawing surface with the background color of the canvas's parent.
+    pub fn clear(&self) {
+        unsafe { ::iup_sys::IupDrawParentBackground(self.canvas.ptr()); }
+    }
+
+    /// Draw a line from `(x1, y1)` to `(x2, y2)`.
+    pub fn draw_line(&self, x1: i32, y1: i32, x2: i32, y2: i32) {
+        unsafe { ::iup_sys::IupDrawLine(self.canvas.ptr(), x1, y1, x2, y2); }
+    }
+
+    /// Draw the outline of the rectangle with its top-left corner at `(x, y)`.
+    pub fn draw_rect(&self, x: i32, y: i32, width: u32, height: u32) {
+        self.canvas.set_const_str_attribute(::attrs::DRAW_STYLE, cstr!("STROKE"));
+        self.rectangle(x, y, width, height);
+    }
+
+    /// Draw a filled rectangle with its top-left corner at `(x, y)`.
+    pub fn fill_rect(&self, x: i32, y: i32, width: u32, height: u32) {
+        self.canvas.set_const_str_attribute(::attrs::DRAW_STYLE, cstr!("FILL"));
+        self.rectangle(x, y, width, height);
+    }
+
+    fn rectangle(&self, x: i32, y: i32, width: u32, height: u32) {
+        let (x2, y2) = (x + width as i32 - 1, y + height as i32 - 1);
+        unsafe { ::iup_sys::IupDrawRectangle(self.canvas.ptr(), x, y, x2, y2); }
+    }
+
+    /// Draw `text` with its top-left corner at `(x, y)`, in the font of the canvas.
+    pub fn draw_text(&self, text: &str, x: i32, y: i32) {
+        let c_text = CString::new(text).unwrap();
+
+        unsafe {
+            ::iup_sys::IupDrawText(
+                self.canvas.ptr(), c_text.as_ptr(), text.len() as i32, x, y, -1, -1
+            );
+        }
+    }
+}
+
+impl Drop for DrawContext {
+    fn drop(&mut self) {
+        unsafe { ::iup_sys::IupDrawEnd(self.canvas.ptr()); }
+    }
+}
@@ -0,0 +1,135 @@
+//! Access to the system clipboard.
+
+use base::BaseWidget;
+use image::Image;
+use utils::cstr::AsCStr;
+use widget_prelude::*;
+
+/// A kind of data that can be on the clipboard, as returned by `Clipboard::available_formats()`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ClipboardFormat {
+    /// Text, read with `Clipboard::get_text()`.
+    Text,
+    /// An image, read with `Clipboard::get_image()`.
+    Image,
+    /// An image in the platform's native bitmap format, which `Clipboard::get_image()` converts
+    /// from.
+    ///
+    /// IUP reads both image formats from the same native data, so this is available exactly
+    /// when `Image` is.
+    NativeImage,
+}
+
+/// The system clipboard, for copying text and images to and from other applications.
+///
+/// For more info, see the [`IupClipboard`][iup-clipboard] documentation.
+///
+/// [iup-clipboard]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupclipboard.html
+pub enum Clipboard {}
+
+impl Clipboard {
+    /// Get the formats of the data currently on the clipboard.
+    ///
+    /// Returns an empty `Vec` if the clipboard is empty or only holds data in other formats.
+    ///
+    /// ##Panics
+    /// If called outside a valid KISS-UI context.
+    pub fn available_formats() -> Vec<ClipboardFormat> {
+        with_clipboard(|clipboard| {
+            let mut formats = Vec::new();
+
+            if clipboard.get_bool_attribute(::attrs::TEXT_AVAILABLE) {
+                formats.push(ClipboardFormat::Text);
+            }
+
+            if clipboard.get_bool_attribute(::attrs::IMAGE_AVAILABLE) {
+                formats.push(ClipboardFormat::Image);
+                formats.push(ClipboardFormat::NativeImage);
+            }
+
+            formats
+        })
+    }
+
+    /// Check if there is text on the clipboard, which `get_text()` would return.
+    ///
+    /// ##Panics
+    /// If called outside a valid KISS-UI context.
+    pub fn has_text() -> bool {
+        with_clipboard(|clipboard| clipboard.get_bool_attribute(::attrs::TEXT_AVAILABLE))
+    }
+
+    /// Check if there is an image on the clipboard, which `get_image()` would return.
+    ///
+    /// ##Panics
+    /// If called outside a valid KISS-UI context.
+    pub fn has_image() -> bool {
+        with_clipboard(|clipboard| clipboard.get_bool_attribute(::attrs::IMAGE_AVAILABLE))
+    }
+
+    /// Replace the contents of the clipboard with `text`.
+    ///
+    /// ##Panics
+    /// If called outside a valid KISS-UI context.
+    pub fn set_text(text: &str) {
+        with_clipboard(|clipboard| clipboard.set_str_attribute(::attrs::TEXT, text))
+    }
+
+    /// Get the text on the clipboard.
+    ///
+    /// Returns `None` if the clipboard is empty or holds something other than text.
+    ///
+    /// ##Panics
+    /// If called outside a valid KISS-UI context.
+    pub fn get_text() -> Option<String> {
+        with_clipboard(|clipboard|
+            if clipboard.get_bool_attribute(::attrs::TEXT_AVAILABLE) {
+                clipboard.get_str_attribute(::attrs::TEXT).map(Into::into)
+            } else {
+                None
+            }
+        )
+    }
+
+    /// Replace the contents of the clipboard with a copy of `image`.
+    ///
+    /// ##Panics
+    /// If called outside a valid KISS-UI context.
+    pub fn set_image(image: Image) {
+        with_clipboard(|clipboard| clipboard.set_attr_handle(::attrs::IMAGE, image))
+    }
+
+    /// Get a copy of the image on the clipboard.
+    ///
+    /// Returns `None` if the clipboard is empty or holds something other than an image.
+    /// The returned image is a new allocation; see the notes on `Image`.
+    ///
+    /// ##Panics
+    /// If called outside a valid KISS-UI context.
+    pub fn get_image() -> Option<Image> {
+        with_clipboard(|clipboard| unsafe {
+            if !clipboard.get_bool_attribute(::attrs::IMAGE_AVAILABLE) {
+                return None;
+            }
+
+            let native = ::iup_sys::IupGetAttribute(clipboard.ptr(), ::attrs::NATIVE_IMAGE.as_cstr());
+
+            if native.is_null() {
+                return None;
+            }
+
+            Image::from_ptr_opt(::iup_sys::IupGetNativeHandleImage(native as *mut _))
+        })
+    }
+}
+
+/// Create a clipboard element, pass it to `with`, and destroy it afterwards.
+fn with_clipboard<F, R>(with: F) -> R where F: FnOnce(BaseWidget) -> R {
+    assert_kiss_running!();
+
+    let clipboard = unsafe { BaseWidget::from_ptr(::iup_sys::IupClipboard()) };
+    let ret = with(clipboard);
+    unsafe { ::iup_sys::IupDestroy(clipboard.ptr()); }
+
+    ret
+}
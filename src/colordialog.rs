@@ -0,0 +1,92 @@
+//! A native dialog for choosing a color.
+
+use widget::Color;
+use widget_prelude::*;
+
+/// A native dialog which lets the user pick a color, optionally with an alpha (opacity) value.
+///
+/// For more info, see the [`IupColorDlg`][iup-colordlg] documentation.
+///
+/// [iup-colordlg]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iupcolordlg.html
+pub struct ColorDialog(IUPPtr);
+
+impl ColorDialog {
+    /// Create a new color dialog, initially showing black.
+    ///
+    /// ##Panics
+    /// If called outside a valid KISS-UI context.
+    pub fn new() -> ColorDialog {
+        assert_kiss_running!();
+
+        unsafe {
+            let ptr = ::iup_sys::IupColorDlg();
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Set the color selected when the dialog opens.
+    pub fn set_initial(self, r: u8, g: u8, b: u8) -> Self {
+        self.set_str_attribute(::attrs::VALUE, Color::new(r, g, b).to_string());
+        self
+    }
+
+    /// Set the color and alpha value selected when the dialog opens. The alpha value is only
+    /// shown if `set_show_alpha(true)` is set.
+    pub fn set_initial_rgba(self, r: u8, g: u8, b: u8, a: u8) -> Self {
+        self.set_initial(r, g, b);
+        self.set_int_attribute(::attrs::ALPHA, a as i32);
+        self
+    }
+
+    /// Set if the user can also pick an alpha value, returned by `popup_rgba()`.
+    pub fn set_show_alpha(self, show_alpha: bool) -> Self {
+        self.set_bool_attribute(::attrs::SHOW_ALPHA, show_alpha);
+        self
+    }
+
+    /// Popup the dialog and block until the user picks a color or cancels.
+    ///
+    /// Returns `None` if the user cancelled.
+    pub fn popup(self) -> Option<(u8, u8, u8)> {
+        self.popup_rgba().map(|(r, g, b, _)| (r, g, b))
+    }
+
+    /// Popup the dialog and block until the user picks a color or cancels, returning the alpha
+    /// value along with the color.
+    ///
+    /// The alpha value is 255 (opaque) unless `set_show_alpha(true)` is set. Returns `None` if
+    /// the user cancelled.
+    pub fn popup_rgba(self) -> Option<(u8, u8, u8, u8)> {
+        unsafe {
+            ::iup_sys::IupPopup(self.ptr(), ::iup_sys::IUP_CENTER, ::iup_sys::IUP_CENTER);
+        }
+
+        if self.get_str_attribute(::attrs::STATUS) != Some("1") {
+            return None;
+        }
+
+        // `"r g b"`, or `"r g b a"` if the alpha value was shown.
+        let value = self.get_str_attribute(::attrs::VALUE)?;
+        let mut channels = value.split_whitespace().map(str::parse::<u8>);
+
+        match (channels.next(), channels.next(), channels.next(), channels.next()) {
+            (Some(Ok(r)), Some(Ok(g)), Some(Ok(b)), None) => Some((r, g, b, self.get_alpha())),
+            (Some(Ok(r)), Some(Ok(g)), Some(Ok(b)), Some(Ok(a))) => Some((r, g, b, a)),
+            _ => None,
+        }
+    }
+
+    fn get_alpha(self) -> u8 {
+        if self.get_bool_attribute(::attrs::SHOW_ALPHA) {
+            self.get_int_attribute(::attrs::ALPHA) as u8
+        } else {
+            255
+        }
+    }
+}
+
+impl Destroy for ColorDialog {}
+
+impl Titled for ColorDialog {}
+
+impl_widget! { ColorDialog, "colordlg" }
@@ -6,9 +6,21 @@
 //! `Vec<BaseWidget>` for the container constructors.
 
 use base::BaseWidget;
+use callback::{CallbackMap, CallbackStatus};
+use utils::cstr::AsCStr;
 use widget_prelude::*;
 
-/// Vertical alignment setting, used by `Horizontal` and `Grid`.
+use iup_sys::CallbackReturn;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::mem;
+
+thread_local! {
+    static TAB_CLOSE_CALLBACKS: CallbackMap<(FlatTabs, usize)> = RefCell::new(HashMap::new());
+}
+
+/// Vertical alignment setting, used by `Horizontal` and `GridBox`.
 #[derive(Copy, Clone)]
 pub enum VAlign {
     Top,
@@ -17,7 +29,7 @@ pub enum VAlign {
 }
 
 impl VAlign {
-    fn as_cstr(self) -> &'static str {
+    pub(crate) fn as_cstr(self) -> &'static str {
         use self::VAlign::*;
 
         match self {
@@ -28,7 +40,7 @@ impl VAlign {
     }
 }
 
-/// Horizontal alignment setting, used by `Vertical` and `Grid`.
+/// Horizontal alignment setting, used by `Vertical` and `GridBox`.
 #[derive(Copy, Clone)]
 pub enum HAlign {
     Left,
@@ -37,7 +49,7 @@ pub enum HAlign {
 }
 
 impl HAlign {
-    fn as_cstr(self) -> &'static str {
+    pub(crate) fn as_cstr(self) -> &'static str {
         use self::HAlign::*;
 
         match self {
@@ -48,6 +60,17 @@ impl HAlign {
     }
 }
 
+/// The alignment of the children of a box container along its cross axis, i.e. horizontally in
+/// a `Vertical` and vertically in a `Horizontal`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Alignment {
+    /// Left in a `Vertical`, top in a `Horizontal`. **Default.**
+    Start,
+    Center,
+    /// Right in a `Vertical`, bottom in a `Horizontal`.
+    End,
+}
+
 /// The behavior of this enum depends on its point of use.
 #[derive(PartialEq, Eq, Copy, Clone)]
 pub enum Orientation {
@@ -68,7 +91,147 @@ impl Orientation {
 }
 
 
-fn raw_handle_vec<B>(widgets: B) -> Vec<*mut ::iup_sys::Ihandle> where B: AsRef<[BaseWidget]> {
+/// Recompute the layout of `container` if it is already on-screen, so attribute changes that
+/// affect its children's sizes or positions take effect immediately.
+pub(crate) fn refresh_if_mapped<W>(container: W) where W: Widget {
+    container.refresh();
+}
+
+/// A widget that holds an arbitrary number of children, which can be added and removed after
+/// construction.
+///
+/// If the container is on-screen, its layout is updated after each change.
+pub trait Container: Widget {
+    /// Add `child` after all the existing children of this container.
+    ///
+    /// `child` must not already have a parent; see `remove_child()`.
+    fn append_child<W>(self, child: W) -> Self where W: Widget {
+        unsafe { ::iup_sys::IupAppend(self.ptr(), child.ptr()); }
+        map_new_child(self, child);
+        self
+    }
+
+    /// Add `new_child` immediately before `ref_child`.
+    ///
+    /// `new_child` must not already have a parent; see `remove_child()`.
+    /// Does nothing if `ref_child` is not a child of this container.
+    fn insert_child<R, W>(self, ref_child: R, new_child: W) -> Self where R: Widget, W: Widget {
+        if is_child_of(self, ref_child) {
+            unsafe { ::iup_sys::IupInsert(self.ptr(), ref_child.ptr(), new_child.ptr()); }
+            map_new_child(self, new_child);
+        }
+
+        self
+    }
+
+    /// Detach `child` from this container.
+    ///
+    /// The child is hidden but not destroyed, so it can be added to another container. If it is
+    /// not going to be reused, call `destroy()` on it to free its resources.
+    ///
+    /// Does nothing if `child` is not a child of this container.
+    fn remove_child<W>(self, child: W) -> Self where W: Widget {
+        if is_child_of(self, child) {
+            unsafe { ::iup_sys::IupDetach(child.ptr()); }
+            self.refresh();
+        }
+
+        self
+    }
+
+    /// Iterate over the children of this container, in order.
+    ///
+    /// The iterator is lazy: each step looks up the child following the previous one, so
+    /// changes made to the container while iterating are reflected.
+    fn children(&self) -> ChildIter {
+        ChildIter {
+            parent: self.ptr(),
+            prev: None,
+        }
+    }
+
+    /// Get the number of children in this container.
+    fn child_count(&self) -> usize {
+        unsafe { ::iup_sys::IupGetChildCount(self.ptr()) as usize }
+    }
+}
+
+/// An iterator over the children of a container. See `Container::children()`.
+pub struct ChildIter {
+    parent: IUPPtr,
+    // `None` before the first child is yielded.
+    prev: Option<IUPPtr>,
+}
+
+impl Iterator for ChildIter {
+    type Item = BaseWidget;
+
+    fn next(&mut self) -> Option<BaseWidget> {
+        // A null pointer here means we ran out of children last time.
+        let prev = match self.prev {
+            Some(prev) if prev.is_null() => return None,
+            Some(prev) => prev,
+            None => ::std::ptr::null_mut(),
+        };
+
+        unsafe {
+            let next = ::iup_sys::IupGetNextChild(self.parent, prev);
+            self.prev = Some(next);
+            BaseWidget::from_ptr_opt(next)
+        }
+    }
+}
+
+fn is_child_of<C, W>(container: C, child: W) -> bool where C: Widget, W: Widget {
+    unsafe { ::iup_sys::IupGetParent(child.ptr()) == container.ptr() }
+}
+
+/// If `container` is already on-screen, create the native handle of its new `child` and make
+/// room for it.
+fn map_new_child<C, W>(container: C, child: W) where C: Widget, W: Widget {
+    if container.get_str_attribute(::attrs::WID).is_some() {
+        unsafe { ::iup_sys::IupMap(child.ptr()); }
+        container.refresh();
+    }
+}
+
+macro_rules! impl_box_spacing {
+    ($self_ty:ident) => (
+        impl $self_ty {
+            /// Set the space between each child, in pixels.
+            ///
+            /// This can be changed after the container is shown.
+            pub fn set_gap(self, gap: u32) -> Self {
+                self.set_int_attribute(::attrs::GAP, gap as i32);
+                refresh_if_mapped(self);
+                self
+            }
+
+            /// Get the space between each child, in pixels.
+            pub fn get_gap(self) -> u32 {
+                self.get_int_attribute(::attrs::GAP) as u32
+            }
+
+            /// Set the space between the edges of the container and its children, in pixels.
+            ///
+            /// This can be changed after the container is shown.
+            pub fn set_margin(self, horizontal: u32, vertical: u32) -> Self {
+                self.set_str_attribute(::attrs::MARGIN, format!("{}x{}", horizontal, vertical));
+                refresh_if_mapped(self);
+                self
+            }
+
+            /// Get the horizontal and vertical space between the edges of the container and its
+            /// children, in pixels.
+            pub fn get_margin(self) -> (u32, u32) {
+                let (horizontal, vertical) = self.get_int2_attribute(::attrs::MARGIN);
+                (horizontal as u32, vertical as u32)
+            }
+        }
+    )
+}
+
+pub(crate) fn raw_handle_vec<B>(widgets: B) -> Vec<*mut ::iup_sys::Ihandle> where B: AsRef<[BaseWidget]> {
     let mut raw_handles: Vec<_> = widgets.as_ref().iter().cloned().map(BaseWidget::ptr).collect();
     raw_handles.push(::std::ptr::null_mut());
     raw_handles
@@ -95,19 +258,37 @@ impl Horizontal {
         }
     }
 
+    /// Set the vertical alignment of the children.
+    ///
+    /// This can be changed after the container is shown. Children that expand vertically fill
+    /// the container's height regardless.
     pub fn set_valign(self, valign: VAlign) -> Self {
-        self.set_const_str_attribute(::attrs::ALIGNMENT_VERT, valign.as_cstr());
+        self.set_const_str_attribute(::attrs::ALIGNMENT, valign.as_cstr());
+        refresh_if_mapped(self);
         self
     }
 
+    /// Set the vertical alignment of the children. See `set_valign()`.
+    pub fn set_alignment(self, alignment: Alignment) -> Self {
+        self.set_valign(match alignment {
+            Alignment::Start => VAlign::Top,
+            Alignment::Center => VAlign::Center,
+            Alignment::End => VAlign::Bottom,
+        })
+    }
+
+    /// Equivalent to `set_gap()`.
     pub fn set_elem_spacing_pixels(self, spacing: u32) -> Self {
-        self.set_str_attribute(::attrs::GAP, spacing.to_string());
-        self
+        self.set_gap(spacing)
     } 
 }
 
+impl Container for Horizontal {}
+
 impl_widget! { Horizontal, "hbox" }
 
+impl_box_spacing! { Horizontal }
+
 /// A container widget that lines up its children from top to bottom.
 pub struct Vertical(IUPPtr);
 
@@ -121,50 +302,113 @@ impl Vertical {
         }
     }
 
+    /// Set the horizontal alignment of the children.
+    ///
+    /// This can be changed after the container is shown. Children that expand horizontally fill
+    /// the container's width regardless.
     pub fn set_halign(self, halign: HAlign) -> Self {
-        self.set_const_str_attribute(::attrs::ALIGNMENT_HORI, halign.as_cstr());
+        self.set_const_str_attribute(::attrs::ALIGNMENT, halign.as_cstr());
+        refresh_if_mapped(self);
         self
     }
 
+    /// Set the horizontal alignment of the children. See `set_halign()`.
+    pub fn set_alignment(self, alignment: Alignment) -> Self {
+        self.set_halign(match alignment {
+            Alignment::Start => HAlign::Left,
+            Alignment::Center => HAlign::Center,
+            Alignment::End => HAlign::Right,
+        })
+    }
+
+    /// Equivalent to `set_gap()`.
     pub fn set_elem_spacing_pixels(self, spacing: u32) -> Self {
-        self.set_str_attribute(::attrs::GAP, spacing.to_string());
-        self
+        self.set_gap(spacing)
     }
 }
 
 
+impl Container for Vertical {}
+
 impl_widget! { Vertical, "vbox" }
 
+impl_box_spacing! { Vertical }
+
 /// A container widget that lines up its children from left to right, and from top to bottom.
-pub struct Grid(IUPPtr);
+///
+/// For more info, see the [`IupGridBox`][iup-gridbox] documentation.
+///
+/// [iup-gridbox]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupgridbox.html
+pub struct GridBox(IUPPtr);
 
-impl Grid {
-    pub fn new<C>(children: C) -> Grid where C: AsRef<[BaseWidget]> {
+impl GridBox {
+    /// Create a new grid with the given vector or array of children, placed in insertion order
+    /// into rows of `num_columns` children. The final row may be shorter.
+    ///
+    /// See the `children![]` macro in this crate for more info.
+    pub fn new<C>(children: C, num_columns: u32) -> GridBox where C: AsRef<[BaseWidget]> {
        let mut raw_handles = raw_handle_vec(children); 
 
-        unsafe {
+        let grid = unsafe {
             let ptr = ::iup_sys::IupGridBoxv(raw_handles.as_mut_ptr());
             Self::from_ptr(ptr)
-        }
+        };
+
+        grid.set_num_div(num_columns)
     }
+
+    /// Set the vertical alignment of the children within each row.
+    ///
+    /// This can be changed after the container is shown.
     pub fn set_valign(self, valign: VAlign) -> Self {
         self.set_const_str_attribute(::attrs::ALIGNMENT_VERT, valign.as_cstr());
+        refresh_if_mapped(self);
         self
     }
 
+    /// Set the horizontal alignment of the children within each column.
+    ///
+    /// This can be changed after the container is shown.
     pub fn set_halign(self, halign: HAlign) -> Self {
         self.set_const_str_attribute(::attrs::ALIGNMENT_HORI, halign.as_cstr());
+        refresh_if_mapped(self);
+        self
+    }
+
+    /// Set the vertical alignment of the children within each row. Equivalent to
+    /// `set_valign()`.
+    pub fn set_vertical_alignment(self, valign: VAlign) -> Self {
+        self.set_valign(valign)
+    }
+
+    /// Set the horizontal alignment of the children within each column. Equivalent to
+    /// `set_halign()`.
+    pub fn set_horizontal_alignment(self, halign: HAlign) -> Self {
+        self.set_halign(halign)
+    }
+
+    /// Set the space between rows, in pixels.
+    pub fn set_gap_lin(self, gap: u32) -> Self {
+        self.set_int_attribute(::attrs::GAP_LIN, gap as i32);
+        self
+    }
+
+    /// Set the space between columns, in pixels.
+    pub fn set_gap_col(self, gap: u32) -> Self {
+        self.set_int_attribute(::attrs::GAP_COL, gap as i32);
         self
     }
 
     /// Based on the orientation, set the number of children to place in a:
     ///
-    /// * `Vertial`: **column**
+    /// * `Vertical`: **column**
     /// * `Horizontal`: **row**
     ///
     /// before beginning the next one.
-    pub fn set_ndiv(self, ndiv: u32) -> Self {
-        self.set_int_attribute(::attrs::NUMDIV, ndiv as i32);
+    ///
+    /// Zero is treated as one.
+    pub fn set_num_div(self, num_div: u32) -> Self {
+        self.set_int_attribute(::attrs::NUM_DIV, num_div.max(1) as i32);
         self
     }
 
@@ -208,13 +452,775 @@ impl Grid {
     ///     </tr>
     /// </table>
     ///
-    pub fn set_orientation(&mut self, orientation: Orientation) -> &mut Self {
+    pub fn set_orientation(self, orientation: Orientation) -> Self {
         self.set_const_str_attribute(::attrs::ORIENTATION, orientation.as_cstr());
         self
     }
 }
 
-impl_widget! { Grid, "matrix" }
+impl Container for GridBox {}
+
+impl_widget! { GridBox, "gridbox" }
+
+/// A container that makes the `Toggle` widgets anywhere within its child behave as a group of
+/// radio buttons, where only one can be checked at a time.
+///
+/// The child is typically a `Vertical` or `Horizontal` containing the toggles.
+pub struct RadioGroup(IUPPtr);
+
+impl RadioGroup {
+    /// Create a new radio group around `child`.
+    pub fn new<W>(child: W) -> RadioGroup where W: Widget {
+        unsafe {
+            let ptr = ::iup_sys::IupRadio(child.ptr());
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Get the currently checked toggle in this group.
+    ///
+    /// Returns `None` if no toggle has been checked yet. Note that IUP automatically checks the
+    /// first toggle in the group when it is shown, unless another one was selected beforehand.
+    pub fn get_selected(self) -> Option<BaseWidget> {
+        let toggle = unsafe {
+            let ptr = ::iup_sys::IupGetAttribute(self.ptr(), ::attrs::VALUE_HANDLE.as_cstr());
+            BaseWidget::from_ptr_opt(ptr as IUPPtr)
+        }?;
+
+        // IUP may fall back to a toggle that is not checked, like the first of the group.
+        if toggle.get_bool_attribute(::attrs::VALUE) { Some(toggle) } else { None }
+    }
+
+    /// Check `toggle`, unchecking the previously checked one.
+    ///
+    /// `toggle` should be a `Toggle` within this group.
+    pub fn set_selected<W>(self, toggle: W) -> Self where W: Widget {
+        self.set_attr_handle(::attrs::VALUE_HANDLE, toggle);
+        self
+    }
+}
+
+impl_widget! { RadioGroup, "radio" }
+
+/// The direction in which the tab titles of a `Tabs` container are written.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TabOrientation {
+    /// **Default.**
+    Horizontal,
+    Vertical,
+}
+
+impl TabOrientation {
+    fn as_cstr(self) -> &'static str {
+        use self::TabOrientation::*;
+
+        match self {
+            Horizontal => cstr!("HORIZONTAL"),
+            Vertical => cstr!("VERTICAL"),
+        }
+    }
+}
+
+/// The side of a `Tabs` container on which the tab titles are placed.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TabType {
+    /// **Default.**
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl TabType {
+    fn as_cstr(self) -> &'static str {
+        use self::TabType::*;
+
+        match self {
+            Top => cstr!("TOP"),
+            Bottom => cstr!("BOTTOM"),
+            Left => cstr!("LEFT"),
+            Right => cstr!("RIGHT"),
+        }
+    }
+}
+
+/// A container widget that shows one of its children at a time, with a row of tabs the user can
+/// click to switch between them.
+///
+/// Tabs are addressed by their zero-based index, in the order the children were added.
+pub struct Tabs(IUPPtr);
+
+impl Tabs {
+    /// Create a new tabs container with the given vector or array of children, one per tab, 
+    /// which may also be empty.
+    ///
+    /// See the `children![]` macro in this crate for more info.
+    pub fn new<C>(children: C) -> Tabs where C: AsRef<[BaseWidget]> {
+        let mut raw_handles = raw_handle_vec(children);
+
+        unsafe {
+            let ptr = ::iup_sys::IupTabsv(raw_handles.as_mut_ptr());
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Add a new tab containing `child` after the existing ones.
+    ///
+    /// This can be called after the container is shown.
+    pub fn append_tab<W>(self, child: W, title: &str) -> Self where W: Widget {
+        unsafe { ::iup_sys::IupAppend(self.ptr(), child.ptr()); }
+
+        self.set_tab_title(self.get_tab_count() - 1, title);
+
+        map_new_child(self, child);
+        self
+    }
+
+    /// Get the number of tabs in this container.
+    pub fn get_tab_count(self) -> usize {
+        unsafe { ::iup_sys::IupGetChildCount(self.ptr()) as usize }
+    }
+
+    /// Set the title of the tab at `idx`.
+    pub fn set_tab_title(self, idx: usize, title: &str) -> Self {
+        self.set_str_attribute_id(::attrs::TAB_TITLE, idx as i32, title);
+        self
+    }
+
+    /// Get the index of the currently shown tab.
+    pub fn get_current_tab(self) -> usize {
+        self.get_int_attribute(::attrs::VALUE_POS) as usize
+    }
+
+    /// Switch to the tab at `idx`.
+    pub fn set_current_tab(self, idx: usize) -> Self {
+        self.set_int_attribute(::attrs::VALUE_POS, idx as i32);
+        self
+    }
+
+    /// Set the direction in which the tab titles are written.
+    pub fn set_tab_orientation(self, orientation: TabOrientation) -> Self {
+        self.set_const_str_attribute(::attrs::TAB_ORIENTATION, orientation.as_cstr());
+        self
+    }
+
+    /// Set the side of the container on which the tab titles are placed.
+    pub fn set_tab_type(self, tab_type: TabType) -> Self {
+        self.set_const_str_attribute(::attrs::TAB_TYPE, tab_type.as_cstr());
+        self
+    }
+
+    /// Set a callback to be invoked when the user switches tabs, with the indices of the
+    /// previous and the new tab, in that order.
+    pub fn set_on_tab_changed<Cb>(self, on_tab_changed: Cb) -> Self 
+    where Cb: ::callback::Callback<(Self, usize, usize)> {
+        callback_impl! {
+            ::attrs::TAB_CHANGE_POS_CB, self, on_tab_changed, Tabs,
+            (new_pos: ::libc::c_int, old_pos: ::libc::c_int) -> (Tabs, usize, usize),
+            |tabs| Some((tabs, old_pos as usize, new_pos as usize))
+        }
+
+        self
+    }
+}
+
+impl Container for Tabs {}
+
+impl_widget! { Tabs, "tabs" }
+
+impl_on_map! { Tabs }
+
+/// A container widget like `Tabs`, with flat, custom-drawn tab titles that can have close
+/// buttons.
+///
+/// Tabs are addressed by their zero-based index, in the order the children were added. None of
+/// them can be closed by the user unless enabled with `set_closable()`.
+///
+/// For more info, see the [`IupFlatTabs`][iup-flattabs] documentation.
+///
+/// [iup-flattabs]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupflattabs.html
+pub struct FlatTabs(IUPPtr);
+
+impl FlatTabs {
+    /// Create a new flat tabs container with the given vector or array of children, one per
+    /// tab, which may also be empty.
+    ///
+    /// See the `children![]` macro in this crate for more info.
+    pub fn new<C>(children: C) -> FlatTabs where C: AsRef<[BaseWidget]> {
+        let mut raw_handles = raw_handle_vec(children);
+
+        let tabs = unsafe {
+            let ptr = ::iup_sys::IupFlatTabsv(raw_handles.as_mut_ptr());
+            Self::from_ptr(ptr)
+        };
+
+        // Closing is always handled by `tab_close_callback()`, which removes the tab.
+        let callback = unsafe {
+            mem::transmute::<
+                extern "C" fn(IUPPtr, ::libc::c_int) -> CallbackReturn,
+                ::iup_sys::Icallback
+            >(tab_close_callback)
+        };
+
+        tabs.set_callback(::attrs::TAB_CLOSE_CB, callback);
+        tabs
+    }
+
+    /// Add a new tab containing `child` after the existing ones. It is not closable.
+    ///
+    /// This can be called after the container is shown.
+    pub fn append_tab<W>(self, child: W, title: &str) -> Self where W: Widget {
+        unsafe { ::iup_sys::IupAppend(self.ptr(), child.ptr()); }
+
+        let idx = self.get_tab_count() - 1;
+        self.set_tab_title(idx, title);
+
+        if self.get_bool_attribute(::attrs::SHOW_CLOSE) {
+            self.set_close_button(idx as i32, false);
+        }
+
+        map_new_child(self, child);
+        self
+    }
+
+    /// Get the number of tabs in this container.
+    pub fn get_tab_count(self) -> usize {
+        unsafe { ::iup_sys::IupGetChildCount(self.ptr()) as usize }
+    }
+
+    /// Set the title of the tab at `idx`.
+    pub fn set_tab_title(self, idx: usize, title: &str) -> Self {
+        self.set_str_attribute_id(::attrs::TAB_TITLE, idx as i32, title);
+        self
+    }
+
+    /// Get the index of the currently shown tab.
+    pub fn get_current_tab(self) -> usize {
+        self.get_int_attribute(::attrs::VALUE_POS) as usize
+    }
+
+    /// Switch to the tab at `idx`.
+    pub fn set_current_tab(self, idx: usize) -> Self {
+        self.set_int_attribute(::attrs::VALUE_POS, idx as i32);
+        self
+    }
+
+    /// Set if the tab at `idx` shows a close button the user can click to remove it.
+    ///
+    /// Does nothing if `idx` is out of range.
+    pub fn set_closable(self, idx: usize, closable: bool) -> Self {
+        let count = self.get_tab_count();
+
+        if idx >= count {
+            return self;
+        }
+
+        // `SHOWCLOSE` enables close buttons on all tabs, so the others must be disabled first.
+        if !self.get_bool_attribute(::attrs::SHOW_CLOSE) {
+            for other in 0 .. count {
+                self.set_close_button(other as i32, false);
+            }
+
+            self.set_bool_attribute(::attrs::SHOW_CLOSE, true);
+        }
+
+        self.set_close_button(idx as i32, closable);
+        self
+    }
+
+    fn set_close_button(self, idx: i32, shown: bool) {
+        self.set_str_attribute_id(::attrs::TAB_SHOW_CLOSE, idx, if shown { "YES" } else { "NO" });
+    }
+
+    /// Set a callback to be invoked when the user switches tabs, with the indices of the
+    /// previous and the new tab, in that order.
+    ///
+    /// This is also invoked when the current tab is closed, with the index the closed tab had
+    /// and the index of the neighboring tab that is shown instead.
+    pub fn set_on_tab_changed<Cb>(self, on_tab_changed: Cb) -> Self
+    where Cb: ::callback::Callback<(Self, usize, usize)> {
+        callback_impl! {
+            ::attrs::TAB_CHANGE_POS_CB, self, on_tab_changed, FlatTabs,
+            (new_pos: ::libc::c_int, old_pos: ::libc::c_int) -> (FlatTabs, usize, usize),
+            |tabs| Some((tabs, old_pos as usize, new_pos as usize))
+        }
+
+        self
+    }
+
+    /// Set a callback to be invoked when the user clicks the close button of a tab, with the
+    /// index of that tab.
+    ///
+    /// Return `true` to close the tab, or `false` to keep it. A closed tab is removed and its
+    /// child destroyed; if it was the current tab, the next one, or else the previous one, is
+    /// shown instead.
+    pub fn set_on_tab_close<F>(self, mut on_tab_close: F) -> Self
+    where F: FnMut((Self, usize)) -> bool + 'static {
+        let on_tab_close = move |args| match on_tab_close(args) {
+            true => CallbackStatus::Default,
+            false => CallbackStatus::Ignore,
+        };
+
+        ::callback::store_callback(&TAB_CLOSE_CALLBACKS, self, Box::new(on_tab_close));
+        self
+    }
+
+    /// Remove the tab at `idx` and destroy its child, switching to a neighboring tab if it was
+    /// the current one.
+    fn close_tab(self, idx: usize) {
+        let count = self.get_tab_count();
+        let current = self.get_current_tab();
+
+        unsafe {
+            let child = ::iup_sys::IupGetChild(self.ptr(), idx as ::libc::c_int);
+            ::iup_sys::IupDestroy(child);
+        }
+
+        refresh_if_mapped(self);
+
+        if idx != current || count == 1 {
+            return;
+        }
+
+        let new = if idx + 1 < count { idx } else { idx - 1 };
+        self.set_current_tab(new);
+
+        // IUP only reports tab changes made by the user, so report this one ourselves.
+        let callback = unsafe {
+            ::iup_sys::IupGetCallback(self.ptr(), ::attrs::TAB_CHANGE_POS_CB.as_cstr())
+        };
+
+        if let Some(callback) = callback {
+            let callback = unsafe {
+                mem::transmute::<
+                    ::iup_sys::Icallback,
+                    extern "C" fn(IUPPtr, ::libc::c_int, ::libc::c_int) -> CallbackReturn
+                >(callback)
+            };
+
+            let status = callback(self.ptr(), new as ::libc::c_int, idx as ::libc::c_int);
+
+            if let CallbackReturn::Close = status {
+                unsafe { ::iup_sys::IupExitLoop(); }
+            }
+        }
+    }
+}
+
+extern "C" fn tab_close_callback(element: IUPPtr, pos: ::libc::c_int) -> CallbackReturn {
+    let tabs = unsafe { FlatTabs::from_ptr(element) };
+
+    if let CallbackStatus::Default =
+        ::callback::invoke_callback(&TAB_CLOSE_CALLBACKS, element, (tabs, pos as usize)) {
+        tabs.close_tab(pos as usize);
+    }
+
+    // The tab was removed already or is kept, so IUP must not hide it.
+    CallbackReturn::Ignore
+}
+
+impl Container for FlatTabs {}
+
+impl_widget! { FlatTabs, "flattabs" }
+
+impl_on_map! { FlatTabs }
+
+/// A container widget that shows two children next to each other, separated by a divider the
+/// user can drag to resize them.
+///
+/// For more info, see the [`IupSplit`][iup-split] documentation.
+///
+/// [iup-split]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupsplit.html
+pub struct Split(IUPPtr);
+
+impl Split {
+    /// Create a new split container with two children.
+    ///
+    /// * `Horizontal`: `first` is placed left of `second`, with a vertical divider.
+    /// * `Vertical`: `first` is placed above `second`, with a horizontal divider.
+    pub fn new<F, S>(first: F, second: S, orientation: Orientation) -> Split
+    where F: Widget, S: Widget {
+        // IUP's orientation is that of the divider, not of the children.
+        let divider = match orientation {
+            Orientation::Horizontal => Orientation::Vertical,
+            Orientation::Vertical => Orientation::Horizontal,
+        };
+
+        unsafe {
+            let ptr = ::iup_sys::IupSplit(first.ptr(), second.ptr());
+            let split = Self::from_ptr(ptr);
+            split.set_const_str_attribute(::attrs::ORIENTATION, divider.as_cstr());
+            split
+        }
+    }
+
+    /// Set the position of the divider, in thousandths of the container's size, from `0` (the
+    /// first child is hidden) to `1000` (the second child is hidden). **Default: `500`.**
+    ///
+    /// This can be set before the container is shown. Values above `1000` are clamped.
+    pub fn set_split_position(self, permille: u32) -> Self {
+        self.set_int_attribute(::attrs::VALUE, permille.min(1000) as i32);
+        self
+    }
+
+    /// Get the position of the divider, in thousandths of the container's size.
+    pub fn get_split_position(self) -> u32 {
+        self.get_int_attribute(::attrs::VALUE) as u32
+    }
+
+    /// Restrict how far the user can drag the divider, in thousandths of the container's size.
+    ///
+    /// ##Panics
+    /// If `min > max` or `max > 1000`.
+    pub fn set_min_max(self, min: u32, max: u32) -> Self {
+        assert!(min <= max && max <= 1000, "expected `min <= max <= 1000`, got {}:{}", min, max);
+        self.set_str_attribute(::attrs::MIN_MAX, format!("{}:{}", min, max));
+        self
+    }
+}
+
+impl_widget! { Split, "split" }
+
+/// A container widget that shows its child through a scrollable viewport.
+///
+/// Scrollbars are added automatically, and are only shown when the child is larger than the
+/// visible area.
+///
+/// For more info, see the [`IupScrollBox`][iup-scrollbox] documentation.
+///
+/// [iup-scrollbox]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupscrollbox.html
+pub struct ScrollBox(IUPPtr);
+
+impl ScrollBox {
+    /// Create a new scroll box around `child`.
+    pub fn new<W>(child: W) -> ScrollBox where W: Widget {
+        unsafe {
+            let ptr = ::iup_sys::IupScrollBox(child.ptr());
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Scroll so the point `(x, y)` of the child, in pixels from its top-left corner, is at the
+    /// top-left corner of the visible area, as far as the child's size allows.
+    pub fn scroll_to(self, x: i32, y: i32) -> Self {
+        self.set_int_attribute(::attrs::POS_X, x);
+        self.set_int_attribute(::attrs::POS_Y, y);
+        self
+    }
+
+    /// Scroll so `widget`, which should be somewhere within the child of this scroll box, is
+    /// brought into view.
+    pub fn scroll_to_child<W>(self, widget: W) -> Self where W: Widget {
+        self.set_attr_handle(::attrs::SCROLL_TO_CHILD_HANDLE, widget);
+        self
+    }
+}
+
+impl_widget! { ScrollBox, "scrollbox" }
+
+/// A container widget that stacks its children on top of each other and shows exactly one of
+/// them at a time, e.g. for pages switched by the application rather than by tabs.
+///
+/// The container is as large as its largest child, so switching does not change the layout.
+/// The children that are not shown are only hidden, so they keep their state, like entered text
+/// or scroll positions, while another one is active.
+///
+/// For more info, see the [`IupZbox`][iup-zbox] documentation.
+///
+/// [iup-zbox]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupzbox.html
+pub struct ZBox(IUPPtr);
+
+impl ZBox {
+    /// Create a new zbox with the given vector or array of children, which may also be empty.
+    /// The first child is shown.
+    ///
+    /// See the `children![]` macro in this crate for more info.
+    pub fn new<C>(children: C) -> ZBox where C: AsRef<[BaseWidget]> {
+        let mut raw_handles = raw_handle_vec(children);
+
+        unsafe {
+            let ptr = ::iup_sys::IupZboxv(raw_handles.as_mut_ptr());
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Show `child`, hiding the previously shown one.
+    ///
+    /// This can be changed after the container is shown. Does nothing if `child` is not a child
+    /// of this container.
+    pub fn set_active_child<W>(self, child: W) -> Self where W: Widget {
+        if is_child_of(self, child) {
+            self.set_attr_handle(::attrs::VALUE_HANDLE, child);
+        }
+
+        self
+    }
+
+    /// Get the currently shown child, or `None` if the container is empty.
+    pub fn get_active_child(self) -> Option<BaseWidget> {
+        self.get_attr_handle(::attrs::VALUE_HANDLE)
+    }
+}
+
+impl Container for ZBox {}
+
+impl_widget! { ZBox, "zbox" }
+
+/// A container widget for a dockable panel: the user can drag its child out by a grip into a
+/// window of its own, which can be docked back with `restore()`.
+///
+/// The child is moved between windows rather than recreated, so it keeps its contents and
+/// callbacks while detached and after it is restored.
+///
+/// For more info, see the [`IupDetachBox`][iup-detachbox] documentation.
+///
+/// [iup-detachbox]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupdetachbox.html
+pub struct DetachBox(IUPPtr);
+
+impl DetachBox {
+    /// Create a new detach box around `child`.
+    pub fn new<W>(child: W) -> DetachBox where W: Widget {
+        unsafe {
+            let ptr = ::iup_sys::IupDetachBox(child.ptr());
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Dock the child back into the place it was detached from.
+    ///
+    /// The window created for it is destroyed. Does nothing if the child is not detached.
+    pub fn restore(self) -> Self {
+        // A null value restores to the original parent.
+        self.set_opt_str_attribute(::attrs::RESTORE, None::<String>);
+        self
+    }
+
+    /// Set a callback to be invoked when the user drags the child out, with the new window that
+    /// will hold it.
+    ///
+    /// The window is shown after the callback returns, so it can be configured, e.g. given a
+    /// title. Return `CallbackStatus::Ignore` to keep the child docked instead.
+    ///
+    /// The window is destroyed when the child is restored, so it should not be kept.
+    pub fn set_on_detached<Cb>(self, on_detached: Cb) -> Self
+    where Cb: ::callback::Callback<(Self, ::dialog::Dialog)> {
+        callback_impl! {
+            ::attrs::DETACHED_CB, self, on_detached, DetachBox,
+            (new_parent: *mut ::iup_sys::Ihandle, _x: ::libc::c_int, _y: ::libc::c_int)
+            -> (DetachBox, ::dialog::Dialog),
+            |detach_box| Some((detach_box, unsafe { ::dialog::Dialog::from_ptr(new_parent) }))
+        }
+
+        self
+    }
+}
+
+impl_widget! { DetachBox, "detachbox" }
+
+/// A container widget that draws a background behind its child, e.g. as a styled content area.
+///
+/// Set the color with `Widget::set_bg_color()`; children that don't set their own background
+/// color inherit it. The container is a native window, so its child is clipped to its area.
+///
+/// For more info, see the [`IupBackgroundBox`][iup-backgroundbox] documentation.
+///
+/// [iup-backgroundbox]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupbackgroundbox.html
+pub struct BackgroundBox(IUPPtr);
+
+impl BackgroundBox {
+    /// Create a new background box around `child`.
+    pub fn new<W>(child: W) -> BackgroundBox where W: Widget {
+        unsafe {
+            let ptr = ::iup_sys::IupBackgroundBox(child.ptr());
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Create a new background box around a `ScrollBox` holding `child`, so the child can be
+    /// scrolled when it is larger than the visible area.
+    pub fn with_scrolling<W>(child: W) -> BackgroundBox where W: Widget {
+        BackgroundBox::new(ScrollBox::new(child))
+    }
+
+    /// Set if a border is drawn around the container. **Default: `false`.**
+    ///
+    /// This must be set before the container is shown; afterwards, it has no effect.
+    pub fn set_border(self, border: bool) -> Self {
+        self.set_bool_attribute(::attrs::BORDER, border);
+        self
+    }
+}
+
+impl_widget! { BackgroundBox, "backgroundbox" }
+
+/// A container widget for a collapsible section: a title bar the user can click to show or hide
+/// the child below it.
+///
+/// While collapsed, the child is hidden and takes no space in the layout. Set the title with
+/// `Titled::set_title()`.
+///
+/// For more info, see the [`IupExpander`][iup-expander] documentation.
+///
+/// [iup-expander]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupexpander.html
+pub struct Expander(IUPPtr);
+
+impl Expander {
+    /// Create a new, expanded expander around `child`.
+    pub fn new<W>(child: W) -> Expander where W: Widget {
+        unsafe {
+            let ptr = ::iup_sys::IupExpander(child.ptr());
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Expand or collapse this expander. **Default: `true`.**
+    ///
+    /// This can be set before the expander is shown, to set its initial state. It does not
+    /// invoke the `set_on_toggle()` callback.
+    pub fn set_expanded(self, expanded: bool) -> Self {
+        let state = if expanded { ::attrs::values::OPEN } else { ::attrs::values::CLOSE };
+        self.set_const_str_attribute(::attrs::STATE, state);
+        refresh_if_mapped(self);
+        self
+    }
+
+    /// Check if this expander is expanded, showing its child.
+    pub fn is_expanded(&self) -> bool {
+        self.get_str_attribute(::attrs::STATE) != Some("CLOSE")
+    }
+
+    /// Set a callback to be invoked when the user expands or collapses this expander, with
+    /// `true` if it is now expanded.
+    pub fn set_on_toggle<Cb>(self, on_toggle: Cb) -> Self
+    where Cb: ::callback::Callback<(Self, bool)> {
+        callback_impl! {
+            ::attrs::ACTION, self, on_toggle, Expander,
+            () -> (Expander, bool),
+            |expander| Some((expander, expander.is_expanded()))
+        }
+
+        self
+    }
+}
+
+impl_widget! { Expander, "expander" }
+
+impl Titled for Expander {}
+
+/// The edge of an `Sbox` that has the resize handle.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Direction {
+    /// The top edge, for resizing the height by dragging up.
+    North,
+    /// The bottom edge, for resizing the height by dragging down.
+    South,
+    /// The right edge, for resizing the width by dragging right. **Default.**
+    East,
+    /// The left edge, for resizing the width by dragging left.
+    West,
+}
+
+impl Direction {
+    fn as_cstr(self) -> &'static str {
+        use self::Direction::*;
+
+        match self {
+            North => cstr!("NORTH"),
+            South => cstr!("SOUTH"),
+            East => cstr!("EAST"),
+            West => cstr!("WEST"),
+        }
+    }
+
+    fn resizes_height(self) -> bool {
+        self == Direction::North || self == Direction::South
+    }
+}
+
+/// A container widget that adds a resize handle to one edge of its child, which the user can
+/// drag to change the child's width or height, e.g. for a side panel.
+///
+/// Dragging respects the minimum and maximum size of the child set with
+/// `Widget::set_min_size()` and `Widget::set_max_size()`.
+///
+/// For more info, see the [`IupSbox`][iup-sbox] documentation.
+///
+/// [iup-sbox]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupsbox.html
+pub struct Sbox(IUPPtr);
+
+impl Sbox {
+    /// Create a new sbox around `child`, with the handle on its right edge.
+    pub fn new<W>(child: W) -> Sbox where W: Widget {
+        unsafe {
+            let ptr = ::iup_sys::IupSbox(child.ptr());
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Set the edge that has the resize handle, which also decides if the handle resizes the
+    /// width or the height of the child.
+    ///
+    /// This can be changed after the container is shown.
+    pub fn set_direction(self, direction: Direction) -> Self {
+        self.set_const_str_attribute(::attrs::DIRECTION, direction.as_cstr());
+        refresh_if_mapped(self);
+        self
+    }
+
+    /// Get the edge that has the resize handle.
+    pub fn get_direction(&self) -> Direction {
+        match self.get_str_attribute(::attrs::DIRECTION) {
+            Some("NORTH") => Direction::North,
+            Some("SOUTH") => Direction::South,
+            Some("WEST") => Direction::West,
+            _ => Direction::East,
+        }
+    }
+
+    /// Set the size of the child along the handle's axis, in pixels: its width for a handle on
+    /// the right or left edge, its height otherwise. The other dimension is left to the layout.
+    ///
+    /// The size is clamped to the minimum and maximum size of the child. Does nothing if the
+    /// sbox has no child.
+    pub fn set_panel_size(self, size: u32) -> Self {
+        let child = match self.child() {
+            Some(child) => child,
+            None => return self,
+        };
+
+        // The limits are in physical pixels, so clamp after converting `size` to them.
+        let size = ::dpi::to_physical(size) as i32;
+        let (min_w, min_h) = child.get_int2_attribute(::attrs::MIN_SIZE);
+        let (max_w, max_h) = child.get_int2_attribute(::attrs::MAX_SIZE);
+
+        let size = if self.get_direction().resizes_height() {
+            format!("x{}", size.max(min_h).min(max_h))
+        } else {
+            format!("{}x", size.max(min_w).min(max_w))
+        };
+
+        child.set_str_attribute(::attrs::RASTERSIZE, size);
+
+        refresh_if_mapped(self);
+        self
+    }
+
+    /// Get the current size of the child along the handle's axis, in pixels, including changes
+    /// made by the user dragging the handle.
+    ///
+    /// Returns `0` if the sbox has no child or has not been shown yet.
+    pub fn get_panel_size(&self) -> u32 {
+        let (width, height) = self.child().map_or((0, 0), |child| child.get_size_pixels());
+
+        if self.get_direction().resizes_height() { height } else { width }
+    }
+
+    fn child(&self) -> Option<BaseWidget> {
+        unsafe { BaseWidget::from_ptr_opt(::iup_sys::IupGetChild(self.ptr(), 0)) }
+    }
+}
+
+impl_widget! { Sbox, "sbox" }
 
 /// Convert a heterogeneous list of widgets into a `Vec<BaseWidget>`,
 /// suitable for passing to any function that takes `AsRef<[BaseWidget]>`, such as a constructor
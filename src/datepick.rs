@@ -0,0 +1,155 @@
+//! Compact date entry with a dropdown calendar.
+
+use callback::{Callback, CallbackMap};
+use widget_prelude::*;
+
+use iup_sys::CallbackReturn;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+thread_local! {
+    static VALUE_CHANGED_CALLBACKS: CallbackMap<(DatePicker, i32, u32, u32)> =
+        RefCell::new(HashMap::new())
+}
+
+/// A date as `(year, month, day)`, with `month` and `day` starting at `1`.
+pub type Date = (i32, u32, u32);
+
+/// A field showing a date, which the user can edit directly or pick from a dropdown calendar.
+///
+/// The initial value is today's date.
+///
+/// For more info, see the [`IupDatePick`][iup-datepick] documentation.
+///
+/// [iup-datepick]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupdatepick.html
+pub struct DatePicker(IUPPtr);
+
+impl DatePicker {
+    /// Create a new date picker showing today's date.
+    pub fn new() -> DatePicker {
+        unsafe {
+            let ptr = ::iup_sys::IupDatePick();
+            let picker = Self::from_ptr(ptr);
+            picker.set_callback(::attrs::VALUE_CHANGED_CB, value_changed_callback);
+            picker
+        }
+    }
+
+    /// Set the date shown by this date picker, clamped to its range, if any.
+    ///
+    /// ##Panics
+    /// If the date does not exist, e.g. because `month` is `13` or `day` is `30` in February.
+    pub fn set_value(self, year: i32, month: u32, day: u32) -> Self {
+        assert_valid_date(year, month, day);
+
+        let date = clamp_date((year, month, day), self.get_range());
+        self.set_str_attribute(::attrs::VALUE, format_date(date));
+        self
+    }
+
+    /// Get the date shown by this date picker as `(year, month, day)`, including changes made by
+    /// the user.
+    pub fn get_value(&self) -> Date {
+        self.get_str_attribute(::attrs::VALUE).and_then(parse_date).unwrap_or((1970, 1, 1))
+    }
+
+    /// Restrict the dates the user can pick to those between `min` and `max`, inclusive, both as
+    /// `(year, month, day)`.
+    ///
+    /// The current date is clamped to the new range. A date outside it that the user enters is
+    /// replaced with the nearest date within it, before the value changed callback is invoked.
+    ///
+    /// ##Panics
+    /// If either date does not exist, or `min` is after `max`.
+    pub fn set_range(self, min: Date, max: Date) -> Self {
+        assert_valid_date(min.0, min.1, min.2);
+        assert_valid_date(max.0, max.1, max.2);
+        assert!(min <= max, "`min` ({:?}) was after `max` ({:?})", min, max);
+
+        self.set_str_attribute(::attrs::KISS_DATE_MIN, format_date(min));
+        self.set_str_attribute(::attrs::KISS_DATE_MAX, format_date(max));
+
+        let (year, month, day) = self.get_value();
+        self.set_value(year, month, day)
+    }
+
+    /// Get the range set with `set_range()`, if any.
+    pub fn get_range(&self) -> Option<(Date, Date)> {
+        let min = self.get_str_attribute(::attrs::KISS_DATE_MIN).and_then(parse_date)?;
+        let max = self.get_str_attribute(::attrs::KISS_DATE_MAX).and_then(parse_date)?;
+        Some((min, max))
+    }
+
+    /// Set a callback to be invoked when the user changes the date, by editing it or picking it
+    /// from the calendar, with the new `year`, `month` and `day`.
+    pub fn set_on_value_changed<Cb>(self, on_value_changed: Cb) -> Self
+    where Cb: Callback<(Self, i32, u32, u32)> {
+        ::callback::store_callback(&VALUE_CHANGED_CALLBACKS, self, Box::new(on_value_changed));
+        self
+    }
+}
+
+extern "C" fn value_changed_callback(element: IUPPtr) -> CallbackReturn {
+    let picker = unsafe { DatePicker::from_ptr(element) };
+
+    let value = picker.get_value();
+    let (year, month, day) = clamp_date(value, picker.get_range());
+
+    if (year, month, day) != value {
+        picker.set_str_attribute(::attrs::VALUE, format_date((year, month, day)));
+    }
+
+    let args = (picker, year, month, day);
+    ::callback::invoke_callback(&VALUE_CHANGED_CALLBACKS, element, args).to_cb_return()
+}
+
+impl_widget! { DatePicker, "datepick" }
+
+impl_on_map! { DatePicker }
+
+impl_on_focus! { DatePicker }
+
+/// Get the number of days in `month` of `year`, in the proleptic Gregorian calendar.
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+
+    match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+pub(crate) fn assert_valid_date(year: i32, month: u32, day: u32) {
+    assert!(
+        (1 ..= 12).contains(&month) && day >= 1 && day <= days_in_month(year, month),
+        "{}-{:02}-{:02} is not a valid date", year, month, day
+    );
+}
+
+/// Format a date for the `VALUE` of `IupDatePick` and `IupCalendar`.
+pub(crate) fn format_date((year, month, day): Date) -> String {
+    format!("{}/{}/{}", year, month, day)
+}
+
+/// Parse a date from the `VALUE` of `IupDatePick` and `IupCalendar`, which is `"year/month/day"`
+/// regardless of how it is displayed.
+pub(crate) fn parse_date(value: &str) -> Option<Date> {
+    let mut parts = value.trim().splitn(3, '/');
+
+    let year = parts.next()?.parse().ok()?;
+    let month = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+
+    Some((year, month, day))
+}
+
+fn clamp_date(date: Date, range: Option<(Date, Date)>) -> Date {
+    match range {
+        Some((min, _)) if date < min => min,
+        Some((_, max)) if date > max => max,
+        _ => date,
+    }
+}
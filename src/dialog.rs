@@ -1,12 +1,36 @@
 //! KISS-UI top-level dialogs (windows)
 
 use base::BaseWidget;
+use callback::{Callback, CallbackMap, CallbackStatus, OnKeyPress};
+use key::{Key, KeyMods};
+use utils::cstr::AsCStr;
 use widget_prelude::*;
 
 use ::iup_sys;
+use iup_sys::CallbackReturn;
 
-use std::ffi::CString;
-use std::ptr;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::ffi::{CStr, CString};
+use std::path::PathBuf;
+use std::{mem, ptr};
+
+thread_local! {
+    static KEY_PRESS_CALLBACKS: CallbackMap<(Dialog, Key, KeyMods)> = RefCell::new(HashMap::new());
+
+    // The accelerators of each dialog, keyed by the dialog and their normalized IUP key code.
+    static ACCELERATORS: CallbackMap<Dialog, (IUPPtr, i32)> = RefCell::new(HashMap::new());
+
+    // The widgets of each dialog with an explicit focus order, in that order.
+    static FOCUS_ORDERS: RefCell<HashMap<IUPPtr, Vec<IUPPtr>>> = RefCell::new(HashMap::new());
+
+    // The widgets that have been put in a focus order, each of which has a destroy hook that
+    // removes it from all of them.
+    static FOCUS_ORDER_WIDGETS: RefCell<HashSet<IUPPtr>> = RefCell::new(HashSet::new());
+
+    // The paths of a drop onto each dialog, collected until IUP has passed the last one.
+    static PENDING_DROPS: RefCell<HashMap<IUPPtr, Vec<PathBuf>>> = RefCell::new(HashMap::new())
+}
 
 /// A top-level dialog that can create a new native window when shown,
 /// and can contain a single widget (which can be a container for many widgets).
@@ -28,7 +52,7 @@ impl Dialog {
 
         unsafe { 
             let ptr = iup_sys::IupDialog(contents.ptr());
-            Self::from_ptr(ptr)
+            Self::from_ptr(ptr).with_key_dispatch()
         }
     }
 
@@ -41,42 +65,740 @@ impl Dialog {
 
         unsafe {
             let ptr = iup_sys::IupDialog(ptr::null_mut());
-            Self::from_ptr(ptr)
+            Self::from_ptr(ptr).with_key_dispatch()
         }
     }
 
-    /// Set the title of this dialog, which will appear in the title bar of the native window.
-    pub fn set_title<T: Into<String>>(self, title: T) -> Self {
-        self.set_str_attribute(::attrs::TITLE, title);
+    /// Start building a dialog, setting up its common options in one call.
+    ///
+    /// Options that are not set on the builder keep IUP's defaults.
+    pub fn builder() -> DialogBuilder {
+        DialogBuilder::default()
+    }
+
+    /// Set the menu bar of this dialog, shown below the title bar.
+    pub fn set_menu(self, menu: ::menu::Menu) -> Self {
+        self.set_attr_handle(::attrs::MENU, menu);
         self
     }
 
-    /// Set the size of this dialog in pixels.
-    pub fn set_size_pixels(self, width: u32, height: u32) -> Self {
-        let rastersize = format!("{}x{}", width, height);
-        self.set_str_attribute(::attrs::RASTERSIZE, rastersize);
+    /// Set the icon of this dialog, shown in the title bar and the taskbar on platforms that
+    /// support it.
+    ///
+    /// This can be called before or after the dialog is shown; the icon of a visible dialog is
+    /// updated immediately.
+    pub fn set_icon(self, icon: ::image::Image) -> Self {
+        self.set_attr_handle(::attrs::ICON, icon);
         self
     }
 
-    /// Get a child of this dialog named by `name`.
+    /// Load an image file and set it as the icon of this dialog. See `set_icon()`.
+    ///
+    /// Requires the `im` feature of this crate; see `Image::from_file()`.
+    #[cfg(feature = "im")]
+    pub fn set_icon_from_file<P>(self, path: P) -> Result<Self, ::image::ImageError>
+    where P: AsRef<::std::path::Path> {
+        ::image::Image::from_file(path).map(|icon| self.set_icon(icon))
+    }
+
+    /// Set whether the user can resize this dialog by dragging its border. **Default:** `true`.
+    ///
+    /// A dialog that is not resizable also has no maximize button, regardless of
+    /// `set_maximizable()`.
+    ///
+    /// This has no effect once the dialog has been shown for the first time.
+    pub fn set_resizable(self, resizable: bool) -> Self {
+        self.set_bool_attribute(::attrs::RESIZE, resizable);
+        self.update_max_box()
+    }
+
+    /// Set whether this dialog has a maximize button in its title bar. **Default:** `true`.
+    ///
+    /// This only takes effect if the dialog is also resizable; see `set_resizable()`. It has no
+    /// effect once the dialog has been shown for the first time.
+    pub fn set_maximizable(self, maximizable: bool) -> Self {
+        self.set_bool_attribute(::attrs::KISS_NO_MAX_BOX, !maximizable);
+        self.update_max_box()
+    }
+
+    /// Set whether this dialog has a minimize button in its title bar. **Default:** `true`.
+    ///
+    /// This has no effect once the dialog has been shown for the first time.
+    pub fn set_minimizable(self, minimizable: bool) -> Self {
+        self.set_bool_attribute(::attrs::MIN_BOX, minimizable);
+        self
+    }
+
+    // `RESIZE` and `_KISS_NOMAXBOX` are unset until changed, which means resizable and
+    // maximizable.
+    fn update_max_box(self) -> Self {
+        let resizable = self.get_str_attribute(::attrs::RESIZE) != Some(::attrs::values::NO);
+        let maximizable = !self.get_bool_attribute(::attrs::KISS_NO_MAX_BOX);
+
+        self.set_bool_attribute(::attrs::MAX_BOX, resizable && maximizable);
+        self
+    }
+
+    /// Show this dialog centered on the monitor it is on, or if it is hidden, the monitor under
+    /// the mouse cursor. A visible dialog is moved.
+    pub fn center(self) -> Self {
+        unsafe { iup_sys::IupMap(self.ptr()); }
+
+        // In physical pixels, like the monitor and window positions.
+        let (width, height) = self.get_int2_attribute(::attrs::RASTERSIZE);
+
+        let anchor = if self.get_bool_attribute(::attrs::VISIBLE) {
+            let (x, y) = self.get_screen_position();
+            (x + width / 2, y + height / 2)
+        } else {
+            cursor_position()
+        };
+
+        match monitor_containing(anchor) {
+            Some((x, y, monitor_width, monitor_height)) => self.set_window_position(
+                x + (monitor_width - width) / 2,
+                y + (monitor_height - height) / 2,
+            ),
+            None => self.show_xy(iup_sys::IUP_CENTER, iup_sys::IUP_CENTER),
+        }
+    }
+
+    /// Show this dialog centered over its parent dialog, or on the main screen if it has none.
+    /// A visible dialog is moved.
+    pub fn center_parent(self) -> Self {
+        self.show_xy(iup_sys::IUP_CENTERPARENT, iup_sys::IUP_CENTERPARENT)
+    }
+
+    /// Show this dialog with its top-left corner at `(x, y)`, or move it there if it is already
+    /// visible.
+    ///
+    /// The coordinates are in pixels relative to the top-left corner of the main screen, and
+    /// extend across all monitors; positions on a monitor left of or above the main screen are
+    /// negative.
+    pub fn set_window_position(self, x: i32, y: i32) -> Self {
+        self.show_xy(x, y)
+    }
+
+    /// Get the position of the top-left corner of this dialog in screen coordinates, as
+    /// accepted by `set_window_position()`.
+    pub fn get_screen_position(self) -> (i32, i32) {
+        (self.get_int_attribute(::attrs::SCREEN_X), self.get_int_attribute(::attrs::SCREEN_Y))
+    }
+
+    /// Maximize this dialog, showing it if it is hidden.
+    pub fn maximize(self) -> Self {
+        self.show_placed(::attrs::values::MAXIMIZED)
+    }
+
+    /// Minimize this dialog to the taskbar, showing it there if it is hidden.
+    pub fn minimize(self) -> Self {
+        self.show_placed(::attrs::values::MINIMIZED)
+    }
+
+    /// Restore this dialog to its normal size and position after `maximize()` or `minimize()`,
+    /// showing it if it is hidden.
+    pub fn restore(self) -> Self {
+        self.show_placed(::attrs::values::NORMAL)
+    }
+
+    /// Get the current state of this dialog's window.
+    ///
+    /// This is queried from the native window, so it also reflects changes made by the user,
+    /// like clicking the maximize button. Only Windows and GTK report maximized and minimized
+    /// windows; elsewhere, a visible dialog is always `WindowState::Normal`.
+    pub fn get_window_state(self) -> WindowState {
+        if self.get_str_attribute(::attrs::MINIMIZED) == Some(::attrs::values::YES) {
+            WindowState::Minimized
+        } else if !self.get_bool_attribute(::attrs::VISIBLE) {
+            WindowState::Hidden
+        } else if self.get_str_attribute(::attrs::MAXIMIZED) == Some(::attrs::values::YES) {
+            WindowState::Maximized
+        } else {
+            WindowState::Normal
+        }
+    }
+
+    // IUP applies `PLACEMENT` on the next show, even if the dialog is already visible, then
+    // resets it to `NORMAL`.
+    fn show_placed(self, placement: &'static str) -> Self {
+        self.set_const_str_attribute(::attrs::PLACEMENT, placement);
+        self.show()
+    }
+
+    fn show_xy(self, x: i32, y: i32) -> Self {
+        unsafe { iup_sys::IupShowXY(self.ptr(), x, y); }
+        self
+    }
+
+    /// Show this dialog centered on the screen as a modal window, blocking interaction with all
+    /// other dialogs until it is closed.
+    ///
+    /// ##Blocks
+    /// Until this dialog is closed, either by the user or by `.close_modal()`. Callbacks
+    /// continue to run while blocked, and may open further modal dialogs, which must be closed
+    /// before this one can return.
+    pub fn popup(self) -> Self {
+        unsafe {
+            iup_sys::IupPopup(self.ptr(), iup_sys::IUP_CENTER, iup_sys::IUP_CENTER);
+        }
+
+        ::event_loop::continue_exit();
+
+        self
+    }
+
+    /// Show this dialog and block until it is closed, either by the user or by
+    /// `.stop_waiting()`.
+    ///
+    /// Unlike `.popup()`, this does not make the dialog modal: all other dialogs stay
+    /// interactive while it is shown.
+    ///
+    /// ##Blocks
+    /// Until this dialog is hidden or destroyed, or `.stop_waiting()` is called on it. Callbacks
+    /// continue to run while blocked, and may call this again for other dialogs. These nested
+    /// calls return in reverse order: if an outer dialog is closed first, its call returns only
+    /// once the inner ones have. If a callback returns `CallbackStatus::Close`, all of them
+    /// return and `show_gui()` exits.
+    ///
+    /// ##Panics
+    /// If called outside a valid KISS-UI context.
+    pub fn show_and_wait(self) {
+        assert_kiss_running!();
+
+        self.show();
+        ::event_loop::wait_for(self);
+    }
+
+    /// Make the `.show_and_wait()` call for this dialog return, without hiding it.
+    ///
+    /// Does nothing if the dialog is not being waited for.
+    pub fn stop_waiting(self) -> Self {
+        ::event_loop::stop_waiting(self.ptr());
+        self
+    }
+
+    /// Close this dialog if it was opened with `.popup()`, making that call return.
+    ///
+    /// The dialog is only hidden, so it can be shown again. From a callback of one of its
+    /// children, the dialog can be retrieved with `Widget::get_dialog()`.
+    pub fn close_modal(self) -> Self {
+        self.hide()
+    }
+
+    /// Set a callback to be invoked when the user tries to close this dialog, e.g. with the
+    /// close button in the title bar.
+    ///
+    /// Return `CloseAction::Prevent` to keep the dialog open, for example after asking the user
+    /// for confirmation with a `MessageDialog`. Returning `CloseAction::Close` or `()` closes the
+    /// dialog as usual.
+    pub fn set_on_close<Cb>(self, on_close: Cb) -> Self where Cb: ::callback::Callback<Self> {
+        callback_impl! { ::attrs::CLOSE_CB, self, on_close, Dialog }
+        self
+    }
+
+    /// Set a callback to be invoked when the user drops files onto this dialog, e.g. from a file
+    /// manager, with the paths of all the files dropped at once and the position of the drop
+    /// relative to the top-left corner of the dialog's client area.
+    pub fn set_on_file_drop<Cb>(self, on_file_drop: Cb) -> Self
+    where Cb: ::callback::Callback<(Self, Vec<PathBuf>, i32, i32)> {
+        self.set_bool_attribute(::attrs::DROP_FILES_TARGET, true);
+
+        // IUP invokes `DROPFILES_CB` once per file, with `idx` counting down to zero.
+        callback_impl! {
+            ::attrs::DROP_FILES_CB, self, on_file_drop, Dialog,
+            (filename: *mut ::libc::c_char, idx: ::libc::c_int, x: ::libc::c_int, y: ::libc::c_int)
+            -> (Dialog, Vec<PathBuf>, i32, i32),
+            |dialog| {
+                let path = unsafe { CStr::from_ptr(filename) }.to_string_lossy().into_owned();
+
+                PENDING_DROPS.with(|drops| {
+                    let mut drops = drops.borrow_mut();
+                    drops.entry(dialog.ptr()).or_insert_with(Vec::new).push(PathBuf::from(path));
+
+                    if idx > 0 {
+                        return None;
+                    }
+
+                    drops.remove(&dialog.ptr()).map(|mut paths| {
+                        paths.reverse();
+                        (dialog, paths, x, y)
+                    })
+                })
+            }
+        }
+
+        self
+    }
+
+    /// Get a child of this dialog named by `name`. Equivalent to `find_child()`.
     ///
     /// Returns `None` if the child was not found.
     pub fn get_child(self, name: &str) -> Option<BaseWidget> {
-        let name = CString::new(name).unwrap();        
+        self.find_child(name)
+    }
+
+    /// Find the widget named `name` with `Widget::set_name()` anywhere within this dialog, at
+    /// any depth.
+    ///
+    /// Names are only looked up among the widgets of this dialog, so the same name can be used
+    /// in different dialogs.
+    ///
+    /// Returns `None` if no widget in this dialog has that name.
+    pub fn find_child(&self, name: &str) -> Option<BaseWidget> {
+        // A name with a nul byte can't have been set through the C API.
+        let name = CString::new(name).ok()?;
 
         unsafe {
             let child_ptr = iup_sys::IupGetDialogChild(self.ptr(), name.as_ptr());
             BaseWidget::from_ptr_opt(child_ptr)
         }
-    } 
+    }
+
+    /// Set a callback to be invoked when `key` is pressed with exactly `mods` held anywhere in
+    /// this dialog, replacing the previous one for that combination, if any.
+    ///
+    /// Accelerators are checked after button hotkeys and before the `OnKeyPress` callback. A key
+    /// press that triggers one is not passed on to the focused widget.
+    ///
+    /// By default, keys that a focused, editable text box uses, like letters or the arrow keys,
+    /// reach the text box instead; see `set_accelerator_precedence()`. Keys like `Escape` or
+    /// `F1` always trigger their accelerator.
+    ///
+    /// ```ignore
+    /// dialog.add_accelerator(Key::Escape, KeyMods::none(), |_| CallbackStatus::Close)
+    ///     .add_accelerator(Key::F(1), KeyMods::none(), |_| show_help());
+    /// ```
+    pub fn add_accelerator<Cb>(self, key: Key, mods: KeyMods, on_accelerator: Cb) -> Self
+    where Cb: Callback<Self> {
+        let ptr = self.ptr();
+        let code = key.to_code(mods);
+
+        let (is_first, prev) = ACCELERATORS.with(|accelerators| {
+            let mut accelerators = accelerators.borrow_mut();
+            let is_first = !accelerators.keys().any(|&(dialog, _)| dialog == ptr);
+            let prev = accelerators.insert((ptr, code), Some(Box::new(on_accelerator)));
+
+            (is_first, prev)
+        });
+
+        // Dropped outside the borrow, in case its captures destroy more widgets.
+        drop(prev);
+
+        if is_first {
+            ::callback::on_destroy(self, |dialog| {
+                let removed: Vec<_> = ACCELERATORS.with(|accelerators|
+                    accelerators.borrow_mut().extract_if(|key, _| key.0 == dialog).collect()
+                );
+                drop(removed);
+            });
+        }
+
+        self
+    }
+
+    /// Remove the accelerator for `key` with `mods` set with `add_accelerator()`, if any.
+    pub fn remove_accelerator(self, key: Key, mods: KeyMods) -> Self {
+        let code = key.to_code(mods);
+
+        let removed = ACCELERATORS.with(|accelerators|
+            accelerators.borrow_mut().remove(&(self.ptr(), code))
+        );
+        drop(removed);
+
+        self
+    }
+
+    /// Set whether accelerators or a focused text box get the keys that both could handle.
+    /// Defaults to `AcceleratorPrecedence::Text`.
+    pub fn set_accelerator_precedence(self, precedence: AcceleratorPrecedence) -> Self {
+        let accelerators_first = precedence == AcceleratorPrecedence::Accelerators;
+        self.set_bool_attribute(::attrs::KISS_ACCEL_FIRST, accelerators_first);
+        self
+    }
+
+    /// Invoke the accelerator of this dialog for the key code passed to `K_ANY`, returning its
+    /// result.
+    ///
+    /// Returns `None` if it has no accelerator for that key, or if the focused widget takes it.
+    fn activate_accelerator(self, code: i32) -> Option<CallbackReturn> {
+        let (key, mods) = (Key::from_code(code), KeyMods::from_code(code));
+
+        if !self.get_bool_attribute(::attrs::KISS_ACCEL_FIRST) && self.text_wants_key(key, mods) {
+            return None;
+        }
+
+        let key = (self.ptr(), key.to_code(mods));
+
+        if !ACCELERATORS.with(|accelerators| accelerators.borrow().contains_key(&key)) {
+            return None;
+        }
+
+        // If it is already running, this swallows the key, as if it had run.
+        let status = ::callback::invoke_callback(&ACCELERATORS, key, self);
+
+        match status {
+            CallbackStatus::Close => Some(CallbackReturn::Close),
+            _ => Some(CallbackReturn::Ignore),
+        }
+    }
+
+    /// Check if the focused widget is an editable text box in this dialog that uses `key` with
+    /// `mods` for editing or moving the caret.
+    fn text_wants_key(self, key: Key, mods: KeyMods) -> bool {
+        let focus = unsafe { BaseWidget::from_ptr_opt(iup_sys::IupGetFocus()) };
+
+        let focus = match focus {
+            Some(focus) => focus,
+            None => return false,
+        };
+
+        let in_dialog = unsafe { iup_sys::IupGetDialog(focus.ptr()) } == self.ptr();
+
+        if !in_dialog || focus.classname().to_bytes() != b"text"
+            || focus.get_bool_attribute(::attrs::READ_ONLY) {
+            return false;
+        }
+
+        if mods.alt || mods.sys {
+            return false;
+        }
+
+        match key {
+            Key::Up | Key::Down | Key::Left | Key::Right | Key::Home | Key::End | Key::PageUp
+            | Key::PageDown | Key::Insert | Key::Delete | Key::Backspace => true,
+            // Select all, the clipboard, undo and redo.
+            Key::Letter(c) if mods.ctrl => "ACVXYZ".contains(c),
+            _ if mods.ctrl => false,
+            Key::Letter(_) | Key::Digit(_) | Key::Char(_) | Key::Space => true,
+            Key::Enter => focus.get_bool_attribute(::attrs::MULTILINE),
+            _ => false,
+        }
+    }
+
+    /// Set the order in which Tab moves the keyboard focus between the widgets of this dialog,
+    /// replacing IUP's default order, which follows the layout. Shift+Tab moves backwards.
+    ///
+    /// Both directions wrap around at the ends of `widgets`. Widgets that are hidden, disabled
+    /// or have `set_tab_stop(false)` are skipped. While the focus is on a widget not in `widgets`,
+    /// Tab moves it to the first one and Shift+Tab to the last one.
+    ///
+    /// Pass an empty slice to restore the default order. See the `children![]` macro in this
+    /// crate for building `widgets`.
+    pub fn set_focus_order<C>(self, widgets: C) -> Self where C: AsRef<[BaseWidget]> {
+        let order: Vec<IUPPtr> = widgets.as_ref().iter().map(|widget| widget.ptr()).collect();
+
+        for &widget in widgets.as_ref() {
+            let is_new = FOCUS_ORDER_WIDGETS.with(|hooked| {
+                hooked.borrow_mut().insert(widget.ptr())
+            });
+
+            // Keep the orders from pointing to destroyed widgets. Only once per widget, however
+            // often it is put in an order.
+            if is_new {
+                ::callback::on_destroy(widget, |widget| {
+                    FOCUS_ORDER_WIDGETS.with(|hooked| hooked.borrow_mut().remove(&widget));
+                    FOCUS_ORDERS.with(|orders| {
+                        for order in orders.borrow_mut().values_mut() {
+                            order.retain(|&ptr| ptr != widget);
+                        }
+                    });
+                });
+            }
+        }
+
+        let is_first = FOCUS_ORDERS.with(|orders| {
+            let mut orders = orders.borrow_mut();
+
+            if order.is_empty() {
+                orders.remove(&self.ptr());
+                false
+            } else {
+                orders.insert(self.ptr(), order).is_none()
+            }
+        });
+
+        if is_first {
+            ::callback::on_destroy(self, |dialog| FOCUS_ORDERS.with(|orders| {
+                orders.borrow_mut().remove(&dialog);
+            }));
+        }
+
+        self
+    }
+
+    /// Move the focus to the next or previous widget in the focus order of this dialog for a
+    /// press of Tab or Shift+Tab.
+    ///
+    /// Returns `None` if the key is not one of those or the dialog has no focus order.
+    fn move_focus(self, code: i32) -> Option<CallbackReturn> {
+        let (key, mods) = (Key::from_code(code), KeyMods::from_code(code));
+
+        if key != Key::Tab || mods.ctrl || mods.alt || mods.sys {
+            return None;
+        }
+
+        let order = FOCUS_ORDERS.with(|orders| orders.borrow().get(&self.ptr()).cloned())?;
+        let focus = unsafe { iup_sys::IupGetFocus() };
+        let len = order.len();
+
+        let start = order.iter().position(|&widget| widget == focus);
+
+        // The positions to visit, wrapping around, starting after the focused widget.
+        let idxs: Vec<usize> = match (start, mods.shift) {
+            (Some(start), false) => (1 ..= len).map(|step| (start + step) % len).collect(),
+            (Some(start), true) => (1 ..= len).map(|step| (start + len - step) % len).collect(),
+            (None, false) => (0 .. len).collect(),
+            (None, true) => (0 .. len).rev().collect(),
+        };
+
+        let next = idxs.into_iter()
+            .map(|idx| unsafe { BaseWidget::from_ptr(order[idx]) })
+            .find(|widget| {
+                widget.is_visible() && widget.is_enabled()
+                    && widget.get_str_attribute(::attrs::CAN_FOCUS) != Some("NO")
+            });
+
+        if let Some(next) = next {
+            next.set_focus();
+        }
+
+        // Swallow the key either way, so IUP doesn't also move the focus in its own order.
+        Some(CallbackReturn::Ignore)
+    }
+
+    // Key presses reach the dialog's `K_ANY` through `key_press_callback()`, which handles
+    // button hotkeys, accelerators and the focus order before invoking the `OnKeyPress`
+    // callback.
+    fn with_key_dispatch(self) -> Self {
+        unsafe {
+            let callback = mem::transmute::<
+                extern "C" fn(IUPPtr, ::libc::c_int) -> CallbackReturn,
+                iup_sys::Icallback
+            >(key_press_callback);
+
+            iup_sys::IupSetCallback(self.ptr(), ::attrs::K_ANY.as_cstr(), callback);
+        }
+
+        self
+    }
+}
+
+impl OnKeyPress for Dialog {
+    fn set_on_key_press<Cb>(self, on_key_press: Cb) -> Self
+    where Cb: Callback<(Self, Key, KeyMods)> {
+        ::callback::store_callback(&KEY_PRESS_CALLBACKS, self, Box::new(on_key_press));
+        self
+    }
+}
+
+extern "C" fn key_press_callback(element: IUPPtr, code: ::libc::c_int) -> CallbackReturn {
+    let dialog = unsafe { Dialog::from_ptr(element) };
+
+    if let Some(status) = ::button::activate_hotkey(dialog, code) {
+        return status;
+    }
+
+    if let Some(status) = dialog.activate_accelerator(code) {
+        return status;
+    }
+
+    if let Some(status) = dialog.move_focus(code) {
+        return status;
+    }
+
+    let args = (dialog, Key::from_code(code), KeyMods::from_code(code));
+
+    // Without a callback, let IUP process the key as if there were no `K_ANY` at all.
+    if KEY_PRESS_CALLBACKS.with(|callbacks| callbacks.borrow().contains_key(&element)) {
+        ::callback::invoke_callback(&KEY_PRESS_CALLBACKS, element, args).to_cb_return()
+    } else {
+        CallbackReturn::Continue
+    }
+}
+
+/// Get the mouse cursor position in screen coordinates.
+fn cursor_position() -> (i32, i32) {
+    global_str(::attrs::CURSOR_POS)
+        .and_then(|pos| {
+            let mut coords = pos.split('x').map(|coord| coord.trim().parse().ok());
+            Some((coords.next()??, coords.next()??))
+        })
+        .unwrap_or((0, 0))
+}
+
+/// Get the bounds `(x, y, width, height)` of the monitor containing `(x, y)`.
+fn monitor_containing((x, y): (i32, i32)) -> Option<(i32, i32, i32, i32)> {
+    // One line of "x y width height" per monitor.
+    let info = global_str(::attrs::MONITORS_INFO)?;
+
+    info.lines()
+        .filter_map(|line| {
+            let bounds: Vec<i32> = line.split_whitespace().filter_map(|n| n.parse().ok()).collect();
+
+            match bounds[..] {
+                [mx, my, width, height] => Some((mx, my, width, height)),
+                _ => None,
+            }
+        })
+        .find(|&(mx, my, width, height)| {
+            x >= mx && x < mx + width && y >= my && y < my + height
+        })
+}
+
+fn global_str(name: &'static str) -> Option<String> {
+    unsafe {
+        let ptr = iup_sys::IupGetGlobal(name.as_cstr());
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+        }
+    }
 }
 
 impl Destroy for Dialog {}
 
-impl_widget! { Dialog, "dialog" }
+impl Titled for Dialog {}
+
+/// A builder for a `Dialog`, created by `Dialog::builder()`.
+#[derive(Default)]
+pub struct DialogBuilder {
+    title: Option<String>,
+    size: Option<(u32, u32)>,
+    min_size: Option<(u32, u32)>,
+    resizable: Option<bool>,
+    icon: Option<::image::Image>,
+    child: Option<BaseWidget>,
+}
+
+impl DialogBuilder {
+    /// Set the title of the dialog. See `Titled::set_title()`.
+    pub fn title<T: Into<String>>(mut self, title: T) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the initial size of the dialog, in pixels. See `Widget::set_size_pixels()`.
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.size = Some((width, height));
+        self
+    }
+
+    /// Set the size, in pixels, that the user cannot resize the dialog below.
+    /// See `Widget::set_min_size()`.
+    pub fn min_size(mut self, width: u32, height: u32) -> Self {
+        self.min_size = Some((width, height));
+        self
+    }
+
+    /// Set whether the user can resize the dialog. See `Dialog::set_resizable()`.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = Some(resizable);
+        self
+    }
+
+    /// Set the icon of the dialog. See `Dialog::set_icon()`.
+    pub fn icon(mut self, icon: ::image::Image) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Set the single child of the dialog. See `Dialog::new()`.
+    pub fn child<W: Widget>(mut self, child: W) -> Self {
+        self.child = Some(child.to_base());
+        self
+    }
+
+    /// Create the dialog. Without a child, this is the same as `Dialog::empty()`.
+    ///
+    /// Like `Dialog::new()`, this does not show the dialog.
+    ///
+    /// ##Panics
+    /// If called outside a valid KISS-UI context.
+    pub fn build(self) -> Dialog {
+        let dialog = match self.child {
+            Some(child) => Dialog::new(child),
+            None => Dialog::empty(),
+        };
+
+        if let Some(title) = self.title {
+            dialog.set_title(title);
+        }
+
+        if let Some((width, height)) = self.size {
+            dialog.set_size_pixels(width, height);
+        }
+
+        if let Some((width, height)) = self.min_size {
+            dialog.set_min_size(width, height);
+        }
+
+        if let Some(resizable) = self.resizable {
+            dialog.set_resizable(resizable);
+        }
+
+        if let Some(icon) = self.icon {
+            dialog.set_icon(icon);
+        }
+
+        dialog
+    }
+}
+
+/// The state of a dialog's window, as returned by `Dialog::get_window_state()`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum WindowState {
+    /// Visible at its normal size.
+    Normal,
+    /// Visible and filling the screen.
+    Maximized,
+    /// Minimized to the taskbar.
+    Minimized,
+    /// Not shown.
+    Hidden,
+}
+
+/// Which of a dialog's accelerators and a focused text box gets a key press that both could
+/// handle, as set by `Dialog::set_accelerator_precedence()`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum AcceleratorPrecedence {
+    /// Keys used for typing, editing and moving the caret reach a focused, editable text box,
+    /// other keys trigger accelerators. **Default.**
+    Text,
+    /// Accelerators are always triggered, even while typing in a text box.
+    Accelerators,
+}
+
+/// The value returned from a `Dialog::set_on_close()` callback, deciding if the dialog closes.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CloseAction {
+    /// Let the dialog close. **Default.**
+    Close,
+    /// Keep the dialog open.
+    Prevent,
+}
+
+impl From<CloseAction> for CallbackStatus {
+    fn from(action: CloseAction) -> CallbackStatus {
+        match action {
+            CloseAction::Close => CallbackStatus::Default,
+            CloseAction::Prevent => CallbackStatus::Ignore,
+        }
+    }
+}
+
+// Tray icons are `IupDialog`s too, but are cast to `TrayIcon` instead.
+impl_widget! { Dialog, "dialog", |widget| !widget.get_bool_attribute(::attrs::TRAY) }
+
+impl_on_map! { Dialog }
 
 impl_on_show! { Dialog }
 
+impl_on_show_state! { Dialog }
+
+impl_on_resize! { Dialog }
+
 /// Popup a message dialog and block until it is closed, by either the OK button or the exit
 /// button.
 pub fn message_popup<T: Into<String>, M: Into<String>>(title: T, message: M) {
@@ -0,0 +1,85 @@
+//! The screen resolution, and the units of pixel sizes on high-DPI displays.
+//!
+//! By default, sizes in pixels are physical pixels, so widgets sized in pixels look smaller on
+//! high-DPI displays. After `set_dpi_aware(true)`, they are logical pixels of 1/96 inch instead,
+//! which are scaled to physical pixels by `scale_factor()`.
+
+use utils::cstr::AsCStr;
+
+use std::cell::Cell;
+use std::ffi::CStr;
+
+/// The resolution at which logical and physical pixels are the same size.
+const BASE_DPI: f32 = 96.0;
+
+thread_local! {
+    static DPI_AWARE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Get the horizontal and vertical resolution of the screen, in pixels per inch.
+///
+/// IUP reports one resolution for the whole screen, so both are the same, and also the same
+/// for all monitors.
+///
+/// ##Panics
+/// If called outside a valid KISS-UI context.
+pub fn screen_dpi() -> (f32, f32) {
+    assert_kiss_running!();
+
+    let dpi = dpi();
+    (dpi, dpi)
+}
+
+/// Get the number of physical pixels per logical pixel of 1/96 inch, e.g. `1.5` at 144 DPI.
+///
+/// ##Panics
+/// If called outside a valid KISS-UI context.
+pub fn scale_factor() -> f32 {
+    assert_kiss_running!();
+    dpi() / BASE_DPI
+}
+
+/// Set whether the pixel sizes passed to and returned from `Widget::set_size_pixels()`,
+/// `get_size_pixels()`, `set_min_size()` and `set_max_size()` are logical pixels, scaled by
+/// `scale_factor()`, or physical pixels. **Default: `false`**, for physical pixels.
+///
+/// IUP itself always works in physical pixels; when this is `true`, KISS-UI converts sizes on
+/// their way to and from IUP. Screen positions, `Canvas` drawing coordinates and sizes in
+/// dialog units are not affected. Call this before creating any widgets, so all sizes are
+/// interpreted the same way.
+///
+/// ##Note
+/// This does not make the process DPI-aware to the operating system; IUP has no global for
+/// that, so whether the system scales the windows of this application is decided by its
+/// manifest or the platform defaults. This only selects the units of the APIs above.
+pub fn set_dpi_aware(dpi_aware: bool) {
+    DPI_AWARE.with(|aware| aware.set(dpi_aware));
+}
+
+/// Check whether pixel sizes are logical pixels. See `set_dpi_aware()`.
+pub fn is_dpi_aware() -> bool {
+    DPI_AWARE.with(Cell::get)
+}
+
+/// Convert a size from the units of the pixel size APIs to the physical pixels used by IUP.
+pub(crate) fn to_physical(size: u32) -> u32 {
+    if is_dpi_aware() { (size as f32 * dpi() / BASE_DPI).round() as u32 } else { size }
+}
+
+/// Convert a size in physical pixels, as used by IUP, to the units of the pixel size APIs.
+pub(crate) fn to_logical(size: u32) -> u32 {
+    if is_dpi_aware() { (size as f32 * BASE_DPI / dpi()).round() as u32 } else { size }
+}
+
+fn dpi() -> f32 {
+    let dpi = unsafe { ::iup_sys::IupGetGlobal(::attrs::SCREEN_DPI.as_cstr()) };
+
+    if dpi.is_null() {
+        return BASE_DPI;
+    }
+
+    unsafe { CStr::from_ptr(dpi) }.to_str().ok()
+        .and_then(|dpi| dpi.trim().parse().ok())
+        .filter(|&dpi: &f32| dpi > 0.0)
+        .unwrap_or(BASE_DPI)
+}
@@ -0,0 +1,152 @@
+//! Dragging data between widgets. See the drag and drop methods on `Widget`.
+
+use base::BaseWidget;
+use callback::{Callback, CallbackMap, CallbackStatus};
+use widget_prelude::*;
+
+use iup_sys::CallbackReturn;
+use libc::{c_char, c_int, c_void};
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::{mem, ptr};
+
+thread_local! {
+    static DRAG_BEGIN: CallbackMap<(BaseWidget, i32, i32)> = RefCell::new(HashMap::new());
+    // Each callback stores the data it returns in `DRAG_PAYLOADS`.
+    static DRAG_DATA: CallbackMap<(BaseWidget, String)> = RefCell::new(HashMap::new());
+    // The data of the drag in progress from each source, between IUP asking for its size and
+    // for the data itself.
+    static DRAG_PAYLOADS: RefCell<HashMap<IUPPtr, Vec<u8>>> = RefCell::new(HashMap::new());
+    static DROP_DATA: CallbackMap<(BaseWidget, String, Vec<u8>, i32, i32)> =
+        RefCell::new(HashMap::new());
+}
+
+pub(crate) fn set_on_drag_begin<W, F>(widget: W, mut on_drag_begin: F)
+where W: Widget, F: FnMut((W, i32, i32)) -> bool + 'static {
+    let on_drag_begin = Box::new(move |(base, x, y): (BaseWidget, i32, i32)| {
+        // `IUP_IGNORE` aborts the drag.
+        if on_drag_begin((unsafe { W::from_ptr(base.ptr()) }, x, y)) {
+            CallbackStatus::Default
+        } else {
+            CallbackStatus::Ignore
+        }
+    });
+
+    ::callback::store_callback(&DRAG_BEGIN, widget, on_drag_begin);
+
+    let callback = unsafe {
+        mem::transmute::<
+            extern "C" fn(IUPPtr, c_int, c_int) -> CallbackReturn,
+            ::iup_sys::Icallback
+        >(drag_begin_callback)
+    };
+
+    widget.set_callback(::attrs::DRAG_BEGIN_CB, callback);
+}
+
+pub(crate) fn set_on_drag_data<W, F>(widget: W, mut on_drag_data: F)
+where W: Widget, F: FnMut((W, String)) -> Vec<u8> + 'static {
+    let on_drag_data = Box::new(move |(base, drag_type): (BaseWidget, String)| {
+        let data = on_drag_data((unsafe { W::from_ptr(base.ptr()) }, drag_type));
+        DRAG_PAYLOADS.with(|payloads| payloads.borrow_mut().insert(base.ptr(), data));
+        CallbackStatus::Default
+    });
+
+    let is_first = DRAG_DATA.with(|callbacks| !callbacks.borrow().contains_key(&widget.ptr()));
+
+    ::callback::store_callback(&DRAG_DATA, widget, on_drag_data);
+
+    if is_first {
+        ::callback::on_destroy(widget, |widget| {
+            DRAG_PAYLOADS.with(|payloads| payloads.borrow_mut().remove(&widget));
+        });
+    }
+
+    unsafe {
+        let size_callback = mem::transmute::<
+            extern "C" fn(IUPPtr, *mut c_char) -> c_int,
+            ::iup_sys::Icallback
+        >(drag_data_size_callback);
+
+        let data_callback = mem::transmute::<
+            extern "C" fn(IUPPtr, *mut c_char, *mut c_void, c_int) -> CallbackReturn,
+            ::iup_sys::Icallback
+        >(drag_data_callback);
+
+        widget.set_callback(::attrs::DRAG_DATA_SIZE_CB, size_callback);
+        widget.set_callback(::attrs::DRAG_DATA_CB, data_callback);
+    }
+}
+
+pub(crate) fn set_on_drop_data<W, Cb>(widget: W, mut on_drop_data: Cb)
+where W: Widget, Cb: Callback<(W, String, Vec<u8>, i32, i32)> {
+    let on_drop_data = Box::new(
+        move |(base, drop_type, data, x, y): (BaseWidget, String, Vec<u8>, i32, i32)|
+            on_drop_data.on_callback((unsafe { W::from_ptr(base.ptr()) }, drop_type, data, x, y))
+    );
+
+    ::callback::store_callback(&DROP_DATA, widget, on_drop_data);
+
+    let callback = unsafe {
+        mem::transmute::<
+            extern "C" fn(IUPPtr, *mut c_char, *mut c_void, c_int, c_int, c_int) -> CallbackReturn,
+            ::iup_sys::Icallback
+        >(drop_data_callback)
+    };
+
+    widget.set_callback(::attrs::DROP_DATA_CB, callback);
+}
+
+fn type_string(drag_type: *mut c_char) -> String {
+    if drag_type.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(drag_type) }.to_string_lossy().into_owned()
+    }
+}
+
+extern "C" fn drag_begin_callback(element: IUPPtr, x: c_int, y: c_int) -> CallbackReturn {
+    let args = (unsafe { BaseWidget::from_ptr(element) }, x, y);
+    ::callback::invoke_callback(&DRAG_BEGIN, element, args).to_cb_return()
+}
+
+extern "C" fn drag_data_size_callback(element: IUPPtr, drag_type: *mut c_char) -> c_int {
+    DRAG_PAYLOADS.with(|payloads| payloads.borrow_mut().remove(&element));
+
+    // The callback is taken out while it runs, so it may set drag callbacks itself.
+    let args = (unsafe { BaseWidget::from_ptr(element) }, type_string(drag_type));
+    ::callback::invoke_callback(&DRAG_DATA, element, args);
+
+    DRAG_PAYLOADS.with(|payloads|
+        payloads.borrow_mut().entry(element).or_insert_with(Vec::new).len() as c_int
+    )
+}
+
+extern "C" fn drag_data_callback(
+    element: IUPPtr, _drag_type: *mut c_char, buf: *mut c_void, size: c_int
+) -> CallbackReturn {
+    let data = DRAG_PAYLOADS.with(|payloads| payloads.borrow_mut().remove(&element));
+
+    if let Some(data) = data {
+        let len = data.len().min(size.max(0) as usize);
+        unsafe { ptr::copy_nonoverlapping(data.as_ptr(), buf as *mut u8, len); }
+    }
+
+    CallbackReturn::Default
+}
+
+extern "C" fn drop_data_callback(
+    element: IUPPtr, drop_type: *mut c_char, buf: *mut c_void, size: c_int, x: c_int, y: c_int
+) -> CallbackReturn {
+    let data = if buf.is_null() || size <= 0 {
+        Vec::new()
+    } else {
+        unsafe { ::std::slice::from_raw_parts(buf as *const u8, size as usize) }.to_vec()
+    };
+
+    let args = (unsafe { BaseWidget::from_ptr(element) }, type_string(drop_type), data, x, y);
+
+    ::callback::invoke_callback(&DROP_DATA, element, args).to_cb_return()
+}
@@ -0,0 +1,272 @@
+//! Hooks into the IUP event loop run by `show_gui()`.
+
+use dialog::Dialog;
+use iup_sys::CallbackReturn;
+use utils::cstr::AsCStr;
+use widget_prelude::*;
+
+use libc::{c_char, c_double, c_int, c_void};
+
+use std::cell::{Cell, RefCell};
+use std::collections::{HashSet, VecDeque};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::{mem, ptr};
+
+/// The value returned from an idle function set with `set_idle()`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum IdleAction {
+    /// Keep the idle function registered, to be invoked again when the event loop is next idle.
+    Continue,
+    /// Unregister the idle function.
+    Stop,
+}
+
+type IdleFn = Box<dyn FnMut() -> IdleAction>;
+
+thread_local! {
+    static IDLE_FN: RefCell<Option<IdleFn>> = const { RefCell::new(None) };
+    // Incremented whenever the idle function is replaced, so one replaced while running is not
+    // restored afterwards.
+    static IDLE_GENERATION: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Set a function to be invoked repeatedly whenever the event loop has no events to process,
+/// replacing the previous one, if any. Pass `None` to unregister it.
+///
+/// This can be called at any time in a valid KISS-UI context, including from within the idle
+/// function itself. `None` must be given a type, e.g. `set_idle(None::<fn() -> IdleAction>)`;
+/// `clear_idle()` is equivalent.
+///
+/// ##Note
+/// While an idle function is registered, the event loop never sleeps, so it should be
+/// unregistered when there is no more background work to do.
+///
+/// ##Panics
+/// If called outside a valid KISS-UI context.
+pub fn set_idle<F>(idle: Option<F>) where F: FnMut() -> IdleAction + 'static {
+    assert_kiss_running!();
+
+    let idle = idle.map(|idle| Box::new(idle) as IdleFn);
+    let callback = if idle.is_some() { Some(idle_callback_ptr()) } else { None };
+
+    IDLE_GENERATION.with(|generation| generation.set(generation.get() + 1));
+    IDLE_FN.with(|idle_fn| *idle_fn.borrow_mut() = idle);
+
+    unsafe { ::iup_sys::IupSetFunction(::attrs::IDLE_ACTION.as_cstr(), callback); }
+}
+
+/// Unregister the idle function set with `set_idle()`, if any.
+///
+/// ##Panics
+/// If called outside a valid KISS-UI context.
+pub fn clear_idle() {
+    set_idle(None::<fn() -> IdleAction>)
+}
+
+fn idle_callback_ptr() -> ::iup_sys::Icallback {
+    unsafe {
+        mem::transmute::<extern "C" fn() -> CallbackReturn, ::iup_sys::Icallback>(idle_callback)
+    }
+}
+
+extern "C" fn idle_callback() -> CallbackReturn {
+    let generation = IDLE_GENERATION.with(Cell::get);
+
+    // Take the function out so it can call `set_idle()` without a double borrow.
+    let mut idle = match IDLE_FN.with(|idle_fn| idle_fn.borrow_mut().take()) {
+        Some(idle) => idle,
+        None => return CallbackReturn::Ignore,
+    };
+
+    let action = idle();
+
+    if IDLE_GENERATION.with(Cell::get) != generation {
+        // Replaced or unregistered by the function itself; that registration stands.
+        return CallbackReturn::Default;
+    }
+
+    match action {
+        IdleAction::Continue => {
+            IDLE_FN.with(|idle_fn| *idle_fn.borrow_mut() = Some(idle));
+            CallbackReturn::Default
+        },
+        // Returning `IUP_IGNORE` makes IUP unregister the idle function.
+        IdleAction::Stop => CallbackReturn::Ignore,
+    }
+}
+
+/// Make `show_gui()` return as soon as the current callback returns, closing all dialogs, e.g.
+/// from a "Quit" menu item.
+///
+/// Any `Dialog::popup()` or `Dialog::show_and_wait()` calls in progress return first.
+///
+/// ##Panics
+/// If called outside a valid KISS-UI context.
+pub fn exit_loop() {
+    assert_kiss_running!();
+
+    EXIT_REQUESTED.with(|exit| exit.set(true));
+    stop_all_waits();
+
+    unsafe { ::iup_sys::IupExitLoop(); }
+}
+
+/// Exit the event loop that a `Dialog::popup()` call returned to, if `exit_loop()` was called
+/// while it ran, since `IupExitLoop()` only exits the innermost loop.
+pub(crate) fn continue_exit() {
+    if EXIT_REQUESTED.with(Cell::get) {
+        unsafe { ::iup_sys::IupExitLoop(); }
+    }
+}
+
+/// Forget an `exit_loop()` call once the main loop has exited. Called by `show_gui()`.
+pub(crate) fn clear_exit() {
+    EXIT_REQUESTED.with(|exit| exit.set(false));
+}
+
+thread_local! {
+    static EXIT_REQUESTED: Cell<bool> = const { Cell::new(false) };
+
+    // The dialog of each `Dialog::show_and_wait()` in progress, innermost last, and whether it
+    // is still waiting.
+    static WAITS: RefCell<Vec<(IUPPtr, bool)>> = const { RefCell::new(Vec::new()) };
+
+    // The dialogs that have been waited for, each of which has a destroy hook that stops the
+    // waits for it.
+    static WAITED_DIALOGS: RefCell<HashSet<IUPPtr>> = RefCell::new(HashSet::new());
+}
+
+/// Run the event loop until `dialog` is hidden or destroyed, or `stop_waiting()` is called for
+/// it. Used by `Dialog::show_and_wait()`.
+pub(crate) fn wait_for(dialog: Dialog) {
+    let depth = WAITS.with(|waits| {
+        let mut waits = waits.borrow_mut();
+        waits.push((dialog.ptr(), true));
+        waits.len() - 1
+    });
+
+    let is_new = WAITED_DIALOGS.with(|waited| waited.borrow_mut().insert(dialog.ptr()));
+
+    // Only once per dialog, however often it is waited for.
+    if is_new {
+        ::callback::on_destroy(dialog, |dialog| {
+            WAITED_DIALOGS.with(|waited| waited.borrow_mut().remove(&dialog));
+            stop_waiting(dialog);
+        });
+    }
+
+    loop {
+        // Checked first, since the dialog may have been destroyed.
+        let waiting = WAITS.with(|waits| waits.borrow()[depth].1);
+
+        if !waiting || !dialog.get_bool_attribute(::attrs::VISIBLE) {
+            break;
+        }
+
+        if unsafe { ::iup_sys::IupLoopStepWait() } == CallbackReturn::Close as c_int {
+            // A callback asked to exit; unwind the enclosing waits, then the main loop.
+            EXIT_REQUESTED.with(|exit| exit.set(true));
+            stop_all_waits();
+            unsafe { ::iup_sys::IupExitLoop(); }
+        }
+    }
+
+    WAITS.with(|waits| waits.borrow_mut().truncate(depth));
+}
+
+/// Make the `wait_for()` calls for `dialog` return.
+pub(crate) fn stop_waiting(dialog: IUPPtr) {
+    WAITS.with(|waits| {
+        for wait in waits.borrow_mut().iter_mut().filter(|wait| wait.0 == dialog) {
+            wait.1 = false;
+        }
+    });
+}
+
+fn stop_all_waits() {
+    WAITS.with(|waits| {
+        for wait in waits.borrow_mut().iter_mut() {
+            wait.1 = false;
+        }
+    });
+}
+
+type PostedFn = Box<dyn FnOnce() + Send>;
+
+struct PostQueue {
+    /// The element that receives the wake-up messages while the event loop is running, as an
+    /// address so it can be shared between threads. Zero if it is not running.
+    handle: usize,
+    pending: VecDeque<PostedFn>,
+}
+
+static POSTED: Mutex<PostQueue> = Mutex::new(PostQueue { handle: 0, pending: VecDeque::new() });
+
+fn lock_posted() -> MutexGuard<'static, PostQueue> {
+    // The queue is never left in an inconsistent state, so a panic elsewhere doesn't matter.
+    POSTED.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Queue `f` to be invoked by the event loop, in a valid KISS-UI context. This can be called
+/// from any thread, e.g. to update the UI with the result of some background work.
+///
+/// Queued functions are invoked in the order they were posted. If the event loop is not
+/// running, they stay queued until `show_gui()` is next called.
+pub fn post<F>(f: F) where F: FnOnce() + Send + 'static {
+    let mut posted = lock_posted();
+    posted.pending.push_back(Box::new(f));
+
+    if posted.handle != 0 {
+        wake(posted.handle);
+    }
+}
+
+fn wake(handle: usize) {
+    unsafe {
+        ::iup_sys::IupPostMessage(handle as IUPPtr, ptr::null(), 0, 0.0, ptr::null_mut());
+    }
+}
+
+/// Start invoking posted functions. Called by `show_gui()` once IUP is initialized.
+pub(crate) fn start_posting() {
+    unsafe {
+        let handle = ::iup_sys::IupUser();
+
+        let callback = mem::transmute::<
+            extern "C" fn(IUPPtr, *mut c_char, c_int, c_double, *mut c_void) -> CallbackReturn,
+            ::iup_sys::Icallback
+        >(post_message_callback);
+
+        ::iup_sys::IupSetCallback(handle, ::attrs::POST_MESSAGE_CB.as_cstr(), callback);
+
+        let mut posted = lock_posted();
+        posted.handle = handle as usize;
+
+        if !posted.pending.is_empty() {
+            wake(posted.handle);
+        }
+    }
+}
+
+/// Stop invoking posted functions. Called by `show_gui()` before IUP is deinitialized.
+pub(crate) fn stop_posting() {
+    let handle = mem::replace(&mut lock_posted().handle, 0);
+
+    if handle != 0 {
+        unsafe { ::iup_sys::IupDestroy(handle as IUPPtr); }
+    }
+}
+
+extern "C" fn post_message_callback(
+    _element: IUPPtr, _s: *mut c_char, _i: c_int, _d: c_double, _p: *mut c_void
+) -> CallbackReturn {
+    // Release the lock before each call so the function can post more.
+    loop {
+        let next = lock_posted().pending.pop_front();
+
+        match next {
+            Some(f) => f(),
+            None => return CallbackReturn::Default,
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Native dialogs for selecting files and directories.
+
+use widget_prelude::*;
+
+use std::path::PathBuf;
+
+/// The kind of selection a `FileDialog` asks the user for.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FileDialogType {
+    /// Select one or more existing files to open. **Default.**
+    Open,
+    /// Select a file to save to, which may or may not exist yet.
+    Save,
+    /// Select an existing directory.
+    Dir,
+}
+
+impl FileDialogType {
+    fn as_cstr(self) -> &'static str {
+        use self::FileDialogType::*;
+
+        match self {
+            Open => cstr!("OPEN"),
+            Save => cstr!("SAVE"),
+            Dir => cstr!("DIR"),
+        }
+    }
+}
+
+/// A native dialog which lets the user pick files or a directory.
+///
+/// For more info, see the [`IupFileDlg`][iup-filedlg] documentation.
+///
+/// [iup-filedlg]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iupfiledlg.html
+pub struct FileDialog(IUPPtr);
+
+impl FileDialog {
+    /// Create a new file dialog for opening a single file.
+    ///
+    /// ##Panics
+    /// If called outside a valid KISS-UI context.
+    pub fn new() -> FileDialog {
+        assert_kiss_running!();
+
+        unsafe {
+            let ptr = ::iup_sys::IupFileDlg();
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Set what kind of selection this dialog asks the user for.
+    pub fn set_dialog_type(self, dialog_type: FileDialogType) -> Self {
+        self.set_const_str_attribute(::attrs::DIALOG_TYPE, dialog_type.as_cstr());
+        self
+    }
+
+    /// Set a filter for the files shown, e.g. `"*.png"`, or `"*.png;*.jpg"` for several
+    /// extensions.
+    pub fn set_filter(self, filter: &str) -> Self {
+        self.set_str_attribute(::attrs::FILTER, filter);
+        self
+    }
+
+    /// Set if the user can select more than one file at once.
+    ///
+    /// Only applies to `FileDialogType::Open`.
+    pub fn set_multiple(self, multiple: bool) -> Self {
+        self.set_bool_attribute(::attrs::MULTIPLE_FILES, multiple);
+        self
+    }
+
+    /// Popup the dialog and block until the user makes a selection or cancels.
+    ///
+    /// Returns `None` if the user cancelled.
+    pub fn popup(self) -> Option<FileResult> {
+        unsafe {
+            ::iup_sys::IupPopup(self.ptr(), ::iup_sys::IUP_CENTER, ::iup_sys::IUP_CENTER);
+        }
+
+        if self.get_int_attribute(::attrs::STATUS) == -1 {
+            return None;
+        }
+
+        self.get_str_attribute(::attrs::VALUE).map(FileResult::parse)
+    }
+}
+
+impl Destroy for FileDialog {}
+
+impl Titled for FileDialog {}
+
+impl_widget! { FileDialog, "filedlg" }
+
+/// The selection made by the user in a `FileDialog`.
+#[derive(Clone, Debug)]
+pub struct FileResult {
+    paths: Vec<PathBuf>,
+}
+
+impl FileResult {
+    fn parse(value: &str) -> FileResult {
+        // With multiple selection, IUP returns the directory followed by the file names,
+        // all separated by `|`.
+        let mut parts = value.split('|').filter(|part| !part.is_empty());
+
+        let first = PathBuf::from(parts.next().unwrap_or(""));
+        let files: Vec<_> = parts.map(|file| first.join(file)).collect();
+
+        FileResult {
+            paths: if files.is_empty() { vec![first] } else { files },
+        }
+    }
+
+    /// Get the selected path, or the first one if multiple files were selected.
+    pub fn path(&self) -> PathBuf {
+        self.paths[0].clone()
+    }
+
+    /// Get all the selected paths.
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.paths.clone()
+    }
+}
@@ -0,0 +1,54 @@
+//! A native dialog for choosing a font.
+
+use widget_prelude::*;
+
+/// A native dialog which lets the user pick a font face, style and size.
+///
+/// For more info, see the [`IupFontDlg`][iup-fontdlg] documentation.
+///
+/// [iup-fontdlg]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iupfontdlg.html
+pub struct FontDialog(IUPPtr);
+
+impl FontDialog {
+    /// Create a new font dialog, initially showing the default font of the system.
+    ///
+    /// ##Panics
+    /// If called outside a valid KISS-UI context.
+    pub fn new() -> FontDialog {
+        assert_kiss_running!();
+
+        unsafe {
+            let ptr = ::iup_sys::IupFontDlg();
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Set the font selected when the dialog opens, in the format used by `Widget::set_font()`,
+    /// e.g. `"Times, Bold 12"`.
+    pub fn set_initial(self, font: &str) -> Self {
+        self.set_str_attribute(::attrs::VALUE, font);
+        self
+    }
+
+    /// Popup the dialog and block until the user picks a font or cancels.
+    ///
+    /// The font is returned in the format used by `Widget::set_font()`, even if it is the same
+    /// as the initial one. Returns `None` if the user cancelled.
+    pub fn popup(self) -> Option<String> {
+        unsafe {
+            ::iup_sys::IupPopup(self.ptr(), ::iup_sys::IUP_CENTER, ::iup_sys::IUP_CENTER);
+        }
+
+        if self.get_str_attribute(::attrs::STATUS) != Some("1") {
+            return None;
+        }
+
+        self.get_str_attribute(::attrs::VALUE).map(String::from)
+    }
+}
+
+impl Destroy for FontDialog {}
+
+impl Titled for FontDialog {}
+
+impl_widget! { FontDialog, "fontdlg" }
@@ -2,7 +2,18 @@
 
 use widget_prelude::*;
 
-use std::mem;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::PathBuf;
+use std::{fmt, io, mem};
+
+thread_local! {
+    // The generation of each live image. A pointer that is missing or maps to another generation
+    // belongs to a destroyed image, even if IUP has since reused it for a new one.
+    static LIVE_IMAGES: RefCell<HashMap<IUPPtr, u64>> = RefCell::new(HashMap::new());
+    static NEXT_GENERATION: Cell<u64> = const { Cell::new(0) };
+}
 
 /// An image buffer allocated by IUP.
 ///
@@ -19,8 +30,9 @@ use std::mem;
 /// 
 /// ##Note: Cloning
 /// Cloning this image does not duplicate its allocation. Thus, destroying one image cloned from
-/// another will destroy them both.
-pub struct Image(IUPPtr);
+/// another will destroy them both. Calling `.destroy()` again on any of the clones does nothing,
+/// and any other use of them panics; see `is_destroyed()`.
+pub struct Image(IUPPtr, u64);
 
 impl Image {
     /// Create a new RGB image buffer from a slice of 3-byte tuples, copying the data into a new
@@ -52,11 +64,199 @@ impl Image {
             Self::from_ptr(ptr)
         }
     } 
+
+    /// Create a new RGBA image buffer from a slice of bytes, 4 per pixel in row-major order,
+    /// copying the data into a new allocation.
+    ///
+    /// ##Panics
+    /// If `pixels.len()` is not equal to `width * height * 4`.
+    pub fn from_rgba(width: u32, height: u32, pixels: &[u8]) -> Image {
+        assert_buffer_len(width, height, 4, pixels);
+        unsafe {
+            let ptr = ::iup_sys::IupImageRGBA(width as i32, height as i32, pixels.as_ptr());
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Create a new RGB image buffer from a slice of bytes, 3 per pixel in row-major order,
+    /// copying the data into a new allocation.
+    ///
+    /// ##Panics
+    /// If `pixels.len()` is not equal to `width * height * 3`.
+    pub fn from_rgb(width: u32, height: u32, pixels: &[u8]) -> Image {
+        assert_buffer_len(width, height, 3, pixels);
+        unsafe {
+            let ptr = ::iup_sys::IupImageRGB(width as i32, height as i32, pixels.as_ptr());
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Get the width and height of this image in pixels.
+    pub fn dimensions(self) -> (u32, u32) {
+        (
+            self.get_int_attribute(::attrs::WIDTH) as u32,
+            self.get_int_attribute(::attrs::HEIGHT) as u32,
+        )
+    }
+
+    /// Load and decode an image file, such as a PNG, BMP or JPEG.
+    ///
+    /// Requires the `im` feature of this crate, which links the IUP-IM and IM libraries.
+    ///
+    /// ##Panics
+    /// If called outside a valid KISS-UI context.
+    #[cfg(feature = "im")]
+    pub fn from_file<P: AsRef<::std::path::Path>>(path: P) -> Result<Image, ImageError> {
+        use std::ffi::{CStr, CString};
+        use std::fs;
+        use utils::cstr::AsCStr;
+
+        const LAST_ERROR: &str = cstr!("IUPIM_LASTERROR");
+
+        assert_kiss_running!();
+
+        let path = path.as_ref();
+
+        // IUP-IM does not distinguish a missing file from an unreadable one.
+        fs::metadata(path).map_err(ImageError::Io)?;
+
+        let c_path = path.to_str()
+            .and_then(|path| CString::new(path).ok())
+            .ok_or_else(|| ImageError::InvalidPath(path.to_owned()))?;
+
+        unsafe {
+            let ptr = ::iup_sys::IupLoadImage(c_path.as_ptr());
+
+            if !ptr.is_null() {
+                return Ok(Self::from_ptr(ptr));
+            }
+
+            let err = ::iup_sys::IupGetGlobal(LAST_ERROR.as_cstr());
+
+            let err = if err.is_null() {
+                "unknown error".to_owned()
+            } else {
+                CStr::from_ptr(err).to_string_lossy().into_owned()
+            };
+
+            Err(ImageError::Decode(err))
+        }
+    }
 }
 
-impl Destroy for Image {}
+impl Image {
+    /// Check if this image, or another image it was cloned from or to, has been destroyed.
+    ///
+    /// Destroyed images may still be passed to `.destroy()`, which does nothing, but any other
+    /// use of them panics.
+    pub fn is_destroyed(self) -> bool {
+        LIVE_IMAGES.with(|live| live.borrow().get(&self.0) != Some(&self.1))
+    }
+}
 
-impl_widget!{ Image, "image" }
+impl Destroy for Image {
+    /// Free this image, unless it has already been destroyed.
+    fn destroy(self) {
+        if !self.is_destroyed() {
+            // The destroy hook set in `from_ptr()` marks it as destroyed.
+            unsafe { ::iup_sys::IupDestroy(self.0); }
+        }
+    }
+}
+
+fn assert_buffer_len(width: u32, height: u32, bytes_per_pixel: usize, pixels: &[u8]) {
+    let expected = width as usize * height as usize * bytes_per_pixel;
+
+    assert!(
+        pixels.len() == expected,
+        "a {}x{} image with {} bytes per pixel needs {} bytes, but the buffer has {}",
+        width, height, bytes_per_pixel, expected, pixels.len()
+    );
+}
+
+/// An error returned by `Image::from_file()`.
+#[derive(Debug)]
+pub enum ImageError {
+    /// The file could not be accessed, e.g. because it does not exist.
+    Io(io::Error),
+    /// The path could not be passed to IUP because it is not valid UTF-8 or contains a nul byte.
+    InvalidPath(PathBuf),
+    /// IUP could not decode the file, e.g. because its format is unsupported. Carries the
+    /// message reported by IUP.
+    Decode(String),
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ImageError::Io(ref err) => write!(f, "could not access image file: {}", err),
+            ImageError::InvalidPath(ref path) =>
+                write!(f, "image path is not valid UTF-8 or contains nul: {}", path.display()),
+            ImageError::Decode(ref msg) => write!(f, "could not decode image file: {}", msg),
+        }
+    }
+}
+
+impl Error for ImageError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ImageError::Io(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// Implemented by hand rather than with `impl_widget!` to track destruction.
+impl IUPWidget for Image {
+    unsafe fn from_ptr(ptr: IUPPtr) -> Self {
+        assert!(!ptr.is_null(), "Failed to construct Image; pointer returned from IUP was null!");
+
+        if let Some(generation) = LIVE_IMAGES.with(|live| live.borrow().get(&ptr).cloned()) {
+            return Image(ptr, generation);
+        }
+
+        let generation = NEXT_GENERATION.with(|next| {
+            let generation = next.get();
+            next.set(generation + 1);
+            generation
+        });
+
+        LIVE_IMAGES.with(|live| live.borrow_mut().insert(ptr, generation));
+
+        let image = Image(ptr, generation);
+
+        // Covers every way the image can be destroyed, not just `Image::destroy()`.
+        ::callback::on_destroy(image, |ptr| {
+            LIVE_IMAGES.with(|live| live.borrow_mut().remove(&ptr));
+        });
+
+        image
+    }
+
+    /// ##Panics
+    /// If this image has been destroyed.
+    fn ptr(self) -> IUPPtr {
+        assert!(!self.is_destroyed(), "attempted to use an `Image` after it was destroyed");
+        self.0
+    }
+
+    fn target_classname() -> &'static str {
+        "image"
+    }
+
+    // `IupImageRGB()` and `IupImageRGBA()` create elements of their own classes.
+    fn is_target_class(classname: &[u8]) -> bool {
+        [&b"image"[..], b"imagergb", b"imagergba"].contains(&classname)
+    }
+}
+
+impl Copy for Image {}
+
+impl Clone for Image {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
 
 /// Cast a slice of bytes to a slice of 3-byte tuples without copying.
 ///
@@ -93,8 +293,7 @@ pub trait ImageContainer: Widget {
         use base::BaseWidget;
 
         self.get_attr_handle(::attrs::IMAGE)
-            .map(BaseWidget::try_downcast::<Image>)
-            .and_then(Result::ok)
+            .and_then(BaseWidget::try_cast::<Image>)
     }
 }
 
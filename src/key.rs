@@ -0,0 +1,208 @@
+//! Keyboard keys and modifiers, as reported to key press callbacks.
+
+use utils::cstr::AsCStr;
+
+use std::ffi::CStr;
+
+// Key codes and modifier flags from `iupkey.h`.
+const K_BS: i32 = 0x08;
+const K_TAB: i32 = 0x09;
+const K_CR: i32 = 0x0D;
+const K_ESC: i32 = 0x1B;
+const K_SP: i32 = 0x20;
+const K_HOME: i32 = 0xFF50;
+const K_LEFT: i32 = 0xFF51;
+const K_UP: i32 = 0xFF52;
+const K_RIGHT: i32 = 0xFF53;
+const K_DOWN: i32 = 0xFF54;
+const K_PGUP: i32 = 0xFF55;
+const K_PGDN: i32 = 0xFF56;
+const K_END: i32 = 0xFF57;
+const K_INS: i32 = 0xFF63;
+const K_DEL: i32 = 0xFFFF;
+const K_F1: i32 = 0xFFBE;
+const K_F12: i32 = 0xFFC9;
+
+const SHIFT_FLAG: i32 = 0x1000_0000;
+const CTRL_FLAG: i32 = 0x2000_0000;
+const ALT_FLAG: i32 = 0x4000_0000;
+const SYS_FLAG: i32 = 0x8000_0000u32 as i32;
+const BASE_MASK: i32 = 0x0FFF_FFFF;
+
+/// A key on the keyboard.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Key {
+    /// A letter key, always reported in uppercase. Use `KeyMods::shift` to tell if Shift was held.
+    Letter(char),
+    /// A digit key on the main keyboard, `0` through `9`.
+    Digit(u8),
+    /// A function key, `F1` through `F12`.
+    F(u8),
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Insert,
+    Delete,
+    Backspace,
+    Tab,
+    Enter,
+    Escape,
+    Space,
+    /// Any other key that produces a printable character, e.g. punctuation.
+    Char(char),
+    /// A key without a named variant, holding its raw IUP key code.
+    Other(i32),
+}
+
+impl Key {
+    /// Convert an IUP key code, as passed to `K_ANY`, to a `Key`, ignoring any modifiers.
+    #[doc(hidden)]
+    pub fn from_code(code: i32) -> Key {
+        use self::Key::*;
+
+        let base = code & BASE_MASK;
+
+        match base {
+            K_BS => Backspace,
+            K_TAB => Tab,
+            K_CR => Enter,
+            K_ESC => Escape,
+            K_SP => Space,
+            K_HOME => Home,
+            K_LEFT => Left,
+            K_UP => Up,
+            K_RIGHT => Right,
+            K_DOWN => Down,
+            K_PGUP => PageUp,
+            K_PGDN => PageDown,
+            K_END => End,
+            K_INS => Insert,
+            K_DEL => Delete,
+            K_F1 ..= K_F12 => F((base - K_F1 + 1) as u8),
+            0x30 ..= 0x39 => Digit((base - 0x30) as u8),
+            0x41 ..= 0x5A | 0x61 ..= 0x7A => Letter((base as u8 as char).to_ascii_uppercase()),
+            0x21 ..= 0x7E => Char(base as u8 as char),
+            _ => Other(base),
+        }
+    }
+
+    /// Convert this key and `mods` to an IUP key code, as used by `K_ANY` and the `KEY`
+    /// attribute.
+    #[doc(hidden)]
+    pub fn to_code(self, mods: KeyMods) -> i32 {
+        use self::Key::*;
+
+        let base = match self {
+            Backspace => K_BS,
+            Tab => K_TAB,
+            Enter => K_CR,
+            Escape => K_ESC,
+            Space => K_SP,
+            Home => K_HOME,
+            Left => K_LEFT,
+            Up => K_UP,
+            Right => K_RIGHT,
+            Down => K_DOWN,
+            PageUp => K_PGUP,
+            PageDown => K_PGDN,
+            End => K_END,
+            Insert => K_INS,
+            Delete => K_DEL,
+            F(n) => K_F1 + n as i32 - 1,
+            Digit(n) => 0x30 + n as i32,
+            // IUP reports modifier combinations with the uppercase letter, and Shift by case.
+            Letter(c) if mods.shift || mods.ctrl || mods.alt || mods.sys =>
+                c.to_ascii_uppercase() as i32,
+            Letter(c) => c.to_ascii_lowercase() as i32,
+            Char(c) => c as i32,
+            Other(code) => code,
+        };
+
+        let mut code = base;
+
+        // Shift is only encoded as a flag for keys that don't change with it.
+        if mods.shift && !is_printable(base) { code |= SHIFT_FLAG; }
+        if mods.ctrl { code |= CTRL_FLAG; }
+        if mods.alt { code |= ALT_FLAG; }
+        if mods.sys { code |= SYS_FLAG; }
+
+        code
+    }
+}
+
+fn is_printable(base: i32) -> bool {
+    base > K_SP && base <= 0x7E
+}
+
+/// The modifier keys held down when a key was pressed.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default, Debug)]
+pub struct KeyMods {
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+    /// The Windows key, or the Command key on OS X.
+    pub sys: bool,
+}
+
+impl KeyMods {
+    /// No modifiers held down.
+    pub fn none() -> KeyMods {
+        KeyMods::default()
+    }
+
+    /// Only Ctrl held down.
+    pub fn ctrl() -> KeyMods {
+        KeyMods { ctrl: true, .. KeyMods::default() }
+    }
+
+    /// Only Shift held down.
+    pub fn shift() -> KeyMods {
+        KeyMods { shift: true, .. KeyMods::default() }
+    }
+
+    /// Only Alt held down.
+    pub fn alt() -> KeyMods {
+        KeyMods { alt: true, .. KeyMods::default() }
+    }
+
+    /// Get the modifiers encoded in an IUP key code, as passed to `K_ANY`.
+    ///
+    /// IUP does not encode Shift for keys that produce a character, so the current modifier
+    /// state is also queried to report it consistently across keys and platforms.
+    #[doc(hidden)]
+    pub fn from_code(code: i32) -> KeyMods {
+        let held = held_modifiers();
+
+        KeyMods {
+            ctrl: code & CTRL_FLAG != 0 || held.ctrl,
+            shift: code & SHIFT_FLAG != 0 || held.shift,
+            alt: code & ALT_FLAG != 0 || held.alt,
+            sys: code & SYS_FLAG != 0 || held.sys,
+        }
+    }
+}
+
+/// Get the modifier keys held down at this moment.
+fn held_modifiers() -> KeyMods {
+    let state = unsafe { ::iup_sys::IupGetGlobal(::attrs::MOD_KEY_STATE.as_cstr()) };
+
+    if state.is_null() {
+        return KeyMods::default();
+    }
+
+    // A string like "SCAY", where each character is replaced by a space if the modifier is
+    // not held.
+    let state = unsafe { CStr::from_ptr(state) }.to_bytes();
+
+    KeyMods {
+        shift: state.contains(&b'S'),
+        ctrl: state.contains(&b'C'),
+        alt: state.contains(&b'A'),
+        sys: state.contains(&b'Y'),
+    }
+}
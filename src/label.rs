@@ -0,0 +1,90 @@
+//! Static labels showing text, an image, or a separator line.
+
+use container::{self, HAlign, Orientation, VAlign};
+use image::{Image, ImageContainer};
+use widget_prelude::*;
+
+use std::ffi::CString;
+use std::ptr;
+
+/// A static widget that renders text within its parent.
+///
+/// For more info, see the [`IupLabel`][iup-label] documentation.
+///
+/// [iup-label]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iuplabel.html
+pub struct Label(IUPPtr);
+
+impl Label {
+    /// Create a label with some text. 
+    pub fn new<S: Into<String>>(text: S) -> Label {
+        let c_text = CString::new(text.into()).unwrap();
+         unsafe {
+            let ptr = ::iup_sys::IupLabel(c_text.as_ptr());
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Create a blank label. The text can be set later.
+    pub fn new_empty() -> Label {
+        unsafe { 
+            let ptr = ::iup_sys::IupLabel(ptr::null());
+            Self::from_ptr(ptr)       
+        }
+    }
+
+    /// Create a label showing an image instead of text.
+    pub fn with_image(image: Image) -> Label {
+        Label::new_empty().set_image(image)
+    }
+
+    /// Create a label that renders as a line, e.g. to divide groups of widgets.
+    ///
+    /// * `Horizontal`: A horizontal line, for use in a `Vertical`.
+    /// * `Vertical`: A vertical line, for use in a `Horizontal`.
+    pub fn separator(orientation: Orientation) -> Label {
+        let label = Label::new_empty();
+        label.set_const_str_attribute(::attrs::SEPARATOR, orientation.as_cstr());
+        label
+    }
+
+    /// Update the text of this label.
+    ///
+    /// If the label is on-screen, the layout of its dialog is updated to fit the new text.
+    pub fn set_text(self, text: &str) -> Self {
+        self.set_str_attribute(::attrs::TITLE, text);
+
+        if let Some(dialog) = self.get_dialog() {
+            container::refresh_if_mapped(dialog);
+        }
+
+        self
+    }
+
+    /// Get the text of this label.
+    pub fn get_text(&self) -> &str {
+        self.get_str_attribute(::attrs::TITLE).unwrap_or("")
+    }
+
+    /// Set the alignment of the text or image within the label's area, which only has a visible
+    /// effect if the label is larger than its contents.
+    pub fn set_alignment(self, halign: HAlign, valign: VAlign) -> Self {
+        // The `as_cstr()` values are nul-terminated.
+        let halign = halign.as_cstr().trim_end_matches('\0');
+        let valign = valign.as_cstr().trim_end_matches('\0');
+
+        self.set_str_attribute(::attrs::ALIGNMENT, format!("{}:{}", halign, valign));
+        self
+    }
+}
+
+impl_widget! { Label, "label" }
+
+impl_on_map! { Label }
+
+impl Titled for Label {}
+
+impl_on_mouse_button! { Label }
+
+impl_on_mouse_motion! { Label }
+
+impl ImageContainer for Label {}
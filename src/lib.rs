@@ -20,8 +20,8 @@
 //! This is most evident with the `Image` struct, which can allocate large backing buffers for image data.
 //!
 //! All types that should be manually freed expose a `.destroy()` method which should be called
-//! when they are no longer being used. This can safely be called multiple times on clones of the
-//! widget types^([citation needed]).
+//! when they are no longer being used. For `Image`, this can safely be called multiple times on
+//! clones, and using a destroyed image panics instead of touching freed memory.
 //!
 //! [iup]: http://webserver2.tecgraf.puc-rio.br/iup/
 
@@ -53,37 +53,96 @@ pub mod callback;
 
 pub mod base;
 pub mod button;
+pub mod calendar;
+pub mod canvas;
+pub mod clipboard;
+pub mod colordialog;
 pub mod container;
+pub mod datepick;
 pub mod dialog;
+pub mod dpi;
+pub mod dragdrop;
+pub mod event_loop;
+pub mod filedialog;
+pub mod fontdialog;
 pub mod image;
+pub mod key;
+pub mod label;
+pub mod link;
+pub mod list;
+#[cfg(feature = "controls")]
+pub mod matrix;
+pub mod menu;
+pub mod message;
+pub mod mouse;
 pub mod progress;
+pub mod slider;
+pub mod spin;
+pub mod state;
+pub mod statusbar;
+pub mod style;
 pub mod text;
 pub mod timer;
+pub mod toggle;
+pub mod tray;
+pub mod tree;
 
 use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
 use std::ptr;
 
 use base::BaseWidget;
 use dialog::Dialog;
 
 mod widget_prelude {
-    pub use widget::{Widget, IUPWidget, Destroy};
+    pub use widget::{Widget, IUPWidget, Destroy, Titled};
 
     pub type IUPPtr = *mut ::iup_sys::Ihandle;
 }
 
+pub use dpi::{is_dpi_aware, screen_dpi, set_dpi_aware};
+pub use event_loop::{clear_idle, exit_loop, post, set_idle, IdleAction};
+pub use state::{app_state, clear_app_state, set_app_state};
+pub use style::{set_global_bg_color, set_global_font, set_theme, Theme};
+pub use timer::{animate, AnimAction};
+
 pub mod prelude {
     pub use base::BaseWidget;
-    pub use dialog::Dialog;
-    pub use container::Orientation;
-    pub use callback::{CallbackStatus, OnClick, OnShow, OnValueChange};
+    pub use dialog::{CloseAction, Dialog};
+    pub use container::{Container, Orientation};
+    pub use callback::{
+        CallbackStatus, OnAction, OnClick, OnFocus, OnKeyPress, OnMap, OnMouseButton,
+        OnMouseMotion, OnResize, OnShow, OnShowState, OnValueChange, ShowState,
+    };
+
+    pub use widget::{Destroy, Expand, Owned, Titled, Widget};
+}
+
+/// An error returned by `show_gui()`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum KissError {
+    /// IUP could not be initialized, e.g. because no display is available. Carries the return
+    /// code of `IupOpen()`.
+    InitFailed(i32),
+}
 
-    pub use widget::{Widget, Destroy};
+impl fmt::Display for KissError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            KissError::InitFailed(code) =>
+                write!(f, "failed to initialize IUP (IupOpen() returned {})", code),
+        }
+    }
 }
 
+impl Error for KissError {}
+
 /// The entry point for KISS-UI. The closure argument should initialize and call `.show()`.
 ///
+/// Returns `Err` if IUP fails to initialize, in which case the closure is not called.
+///
 /// ##Blocks
 /// Until all KISS-UI dialogs are closed.
 ///
@@ -107,22 +166,34 @@ pub mod prelude {
 ///
 /// Since no widget types are `Send`, this bound prevents this from happening without requiring
 /// all widget methods to check if they were invoked in a valid context.
-pub fn show_gui<F>(init_fn: F) where F: FnOnce() -> Dialog + Send {
+pub fn show_gui<F>(init_fn: F) -> Result<(), KissError> where F: FnOnce() -> Dialog + Send {
     use ::utils::cstr::AsCStr;
     use ::widget::Widget;
 
     unsafe { 
-        assert!(iup_sys::IupOpen(ptr::null(), ptr::null()) == 0);
+        let code = iup_sys::IupOpen(ptr::null(), ptr::null());
+
+        if code != iup_sys::IUP_NOERROR {
+            return Err(KissError::InitFailed(code));
+        }
+
         // Force IUP to always use UTF-8
         iup_sys::IupSetGlobal(::attrs::UTF8_MODE.as_cstr(), ::attrs::values::YES.as_cstr());
+
+        #[cfg(feature = "controls")]
+        iup_sys::IupControlsOpen();
     }
 
     KISS_RUNNING.with(|state| state.set(true));
 
+    event_loop::start_posting();
+
     init_fn().show();
 
     unsafe { 
         iup_sys::IupMainLoop();
+        event_loop::clear_exit();
+        event_loop::stop_posting();
         iup_sys::IupClose();
     }
 
@@ -132,6 +203,8 @@ pub fn show_gui<F>(init_fn: F) where F: FnOnce() -> Dialog + Send {
     WIDGET_STORE.with(|store| {
         *store.borrow_mut() = HashMap::new();
     });
+
+    Ok(())
 }
 
 fn kiss_running() -> bool {
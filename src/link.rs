@@ -0,0 +1,94 @@
+//! Clickable hyperlinks.
+
+use callback::{Callback, CallbackStatus};
+use widget_prelude::*;
+
+use std::ffi::{CStr, CString};
+
+/// A label rendered as a hyperlink, underlined and with a hand cursor, that opens a URL in the
+/// system browser when clicked.
+///
+/// For more info, see the [`IupLink`][iup-link] documentation.
+///
+/// [iup-link]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iuplink.html
+pub struct Link(IUPPtr);
+
+impl Link {
+    /// Create a link to `url`, displaying `text`, which may differ from the URL.
+    pub fn new<U: Into<String>, T: Into<String>>(url: U, text: T) -> Link {
+        let c_url = CString::new(url.into()).unwrap();
+        let c_text = CString::new(text.into()).unwrap();
+
+        unsafe {
+            let ptr = ::iup_sys::IupLink(c_url.as_ptr(), c_text.as_ptr());
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Set the URL opened when this link is clicked.
+    pub fn set_url(self, url: &str) -> Self {
+        self.set_str_attribute(::attrs::URL, url);
+        self
+    }
+
+    /// Get the URL opened when this link is clicked.
+    pub fn get_url(&self) -> &str {
+        self.get_str_attribute(::attrs::URL).unwrap_or("")
+    }
+
+    /// Update the text of this link.
+    pub fn set_text(self, text: &str) -> Self {
+        self.set_str_attribute(::attrs::TITLE, text);
+        self
+    }
+
+    /// Get the text of this link.
+    pub fn get_text(&self) -> &str {
+        self.get_str_attribute(::attrs::TITLE).unwrap_or("")
+    }
+
+    /// Set a callback to be invoked when this link is clicked, with its URL.
+    ///
+    /// Afterwards, the URL is opened in the system browser, unless the callback returns
+    /// `LinkAction::Handled`. Returning `LinkAction::Open` or `()` opens it as usual.
+    pub fn set_on_click<Cb>(self, on_click: Cb) -> Self where Cb: Callback<(Self, String)> {
+        callback_impl! {
+            ::attrs::ACTION, self, on_click, Link,
+            (url: *mut ::libc::c_char) -> (Link, String),
+            |link| {
+                let url = unsafe { CStr::from_ptr(url) }.to_string_lossy().into_owned();
+                Some((link, url))
+            };
+            invoke_action_callback
+        }
+
+        self
+    }
+}
+
+impl_widget! { Link, "link" }
+
+impl_on_map! { Link }
+
+impl_on_action! { Link, ::attrs::ACTION, (_url: *mut ::libc::c_char) }
+
+/// The text of the link.
+impl Titled for Link {}
+
+/// The value returned from a `Link::set_on_click()` callback, deciding if the URL is opened.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum LinkAction {
+    /// Open the URL in the system browser. **Default.**
+    Open,
+    /// Don't open the URL, e.g. because the callback handled it.
+    Handled,
+}
+
+impl From<LinkAction> for CallbackStatus {
+    fn from(action: LinkAction) -> CallbackStatus {
+        match action {
+            LinkAction::Open => CallbackStatus::Default,
+            LinkAction::Handled => CallbackStatus::Ignore,
+        }
+    }
+}
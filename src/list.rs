@@ -0,0 +1,198 @@
+//! Scrollable lists of selectable string items.
+
+use widget_prelude::*;
+
+use ::callback::Callback;
+
+use std::ptr;
+
+/// A widget that renders a scrollable list of strings, which the user can select from.
+///
+/// Items are addressed by their zero-based index in the list.
+///
+/// For more info, see the [`IupList`][iup-list] documentation.
+///
+/// [iup-list]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iuplist.html
+pub struct List(IUPPtr);
+
+impl List {
+    /// Create a new, empty list.
+    pub fn new() -> List {
+        unsafe {
+            let ptr = ::iup_sys::IupList(ptr::null());
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Add an item to the end of the list.
+    pub fn append_item(self, item: &str) -> Self {
+        self.set_str_attribute(::attrs::APPEND_ITEM, item);
+        self
+    }
+
+    /// Insert an item before the one currently at `idx`.
+    ///
+    /// If `idx` is equal to the item count, the item is appended to the end of the list.
+    /// Does nothing if `idx` is greater than the item count.
+    pub fn insert_item(self, idx: usize, item: &str) -> Self {
+        let count = self.get_item_count();
+
+        if idx == count {
+            self.append_item(item);
+        } else if idx < count {
+            self.set_str_attribute_id(::attrs::INSERT_ITEM, idx as i32 + 1, item);
+        }
+
+        self
+    }
+
+    /// Remove the item at `idx`, shifting all following items up by one.
+    ///
+    /// Does nothing if `idx` is out of range.
+    pub fn remove_item(self, idx: usize) -> Self {
+        if idx < self.get_item_count() {
+            self.set_int_attribute(::attrs::REMOVE_ITEM, idx as i32 + 1);
+        }
+
+        self
+    }
+
+    /// Remove all items from the list.
+    pub fn clear(self) -> Self {
+        self.set_const_str_attribute(::attrs::REMOVE_ITEM, ::attrs::values::ALL);
+        self
+    }
+
+    /// Replace all the items in this list with the ones yielded by `items`.
+    pub fn set_items<I>(self, items: I) -> Self where I: IntoIterator, I::Item: Into<String> {
+        self.clear();
+
+        for item in items {
+            self.set_str_attribute(::attrs::APPEND_ITEM, item);
+        }
+
+        self
+    }
+
+    /// Get the item at `idx`, if it exists.
+    pub fn get_item(&self, idx: usize) -> Option<&str> {
+        self.get_str_attribute_id(::attrs::ITEM, idx as i32 + 1)
+    }
+
+    /// Get the number of items in this list.
+    pub fn get_item_count(self) -> usize {
+        self.get_int_attribute(::attrs::COUNT) as usize
+    }
+
+    /// Set if the user can select more than one item at a time.
+    ///
+    /// This must be set before the list is shown; afterwards, it has no effect.
+    pub fn set_multiple(self, multiple: bool) -> Self {
+        self.set_bool_attribute(::attrs::MULTIPLE, multiple);
+        self
+    }
+
+    /// Check whether this list allows more than one item to be selected at a time.
+    pub fn is_multiple(&self) -> bool {
+        self.get_bool_attribute(::attrs::MULTIPLE)
+    }
+
+    /// Get the index of the selected item.
+    ///
+    /// Returns `None` if no item is selected or the list is empty. If the list allows multiple
+    /// selection, this returns the first selected item.
+    pub fn get_selected_index(&self) -> Option<usize> {
+        if self.is_multiple() {
+            return self.get_selected_indices().into_iter().next();
+        }
+
+        match self.get_int_attribute(::attrs::VALUE) {
+            idx if idx > 0 => Some(idx as usize - 1),
+            _ => None,
+        }
+    }
+
+    /// Get the indices of all selected items, in ascending order.
+    ///
+    /// In single-selection mode, this will return at most one index.
+    pub fn get_selected_indices(&self) -> Vec<usize> {
+        if !self.is_multiple() {
+            return self.get_selected_index().into_iter().collect();
+        }
+
+        self.get_str_attribute(::attrs::VALUE).unwrap_or("")
+            .bytes()
+            .enumerate()
+            .filter(|&(_, mark)| mark == b'+')
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// Select the item at `idx`, deselecting all others.
+    ///
+    /// Does nothing if `idx` is out of range.
+    pub fn set_selected_index(self, idx: usize) -> Self {
+        let count = self.get_item_count();
+
+        if idx >= count {
+            return self;
+        }
+
+        if self.is_multiple() {
+            let marks: String = (0 .. count).map(|i| if i == idx { '+' } else { '-' }).collect();
+            self.set_str_attribute(::attrs::VALUE, marks);
+        } else {
+            self.set_int_attribute(::attrs::VALUE, idx as i32 + 1);
+        }
+
+        self
+    }
+
+    /// Set a callback to be invoked when an item is selected by the user, with the index of the
+    /// newly selected item.
+    ///
+    /// In multiple-selection mode, this is invoked once for every newly selected item.
+    pub fn set_on_selection_changed<Cb>(self, on_selection_changed: Cb) -> Self
+    where Cb: Callback<(Self, usize)> {
+        callback_impl! {
+            ::attrs::ACTION, self, on_selection_changed, List,
+            (_text: *mut ::libc::c_char, item: ::libc::c_int, state: ::libc::c_int) -> (List, usize),
+            |list| if state == 1 { Some((list, item as usize - 1)) } else { None }
+        }
+
+        self
+    }
+
+    /// Set a callback to be invoked when the user double-clicks an item, with the index of that
+    /// item.
+    ///
+    /// The first click of a double-click on an unselected item also selects it, which is reported
+    /// to the `set_on_selection_changed()` callback once; the second click is not.
+    pub fn set_on_double_click<Cb>(self, on_double_click: Cb) -> Self
+    where Cb: Callback<(Self, usize)> {
+        callback_impl! {
+            ::attrs::DBLCLICK_CB, self, on_double_click, List,
+            (item: ::libc::c_int, _text: *mut ::libc::c_char) -> (List, usize),
+            |list| if item > 0 { Some((list, item as usize - 1)) } else { None };
+            invoke_action_callback
+        }
+
+        self
+    }
+}
+
+impl_widget! { List, "list" }
+
+impl_on_map! { List }
+
+impl_on_action! {
+    List, ::attrs::DBLCLICK_CB, (_item: ::libc::c_int, _text: *mut ::libc::c_char)
+}
+
+impl_on_key_press! { List }
+
+impl_on_focus! { List }
+
+impl_on_mouse_button! { List }
+
+impl_on_mouse_motion! { List }
@@ -0,0 +1,171 @@
+//! Grids of editable text cells, for tabular data.
+//!
+//! Requires the `controls` feature of this crate.
+
+use widget_prelude::*;
+
+use callback::Callback;
+
+use std::ffi::CStr;
+use std::ptr;
+
+/// A widget that shows a grid of text cells, with optional titles above each column and beside
+/// each row, which the user can edit.
+///
+/// Rows and columns are addressed by their zero-based index, not including the titles.
+///
+/// For more info, see the [`IupMatrix`][iup-matrix] documentation.
+///
+/// [iup-matrix]: http://webserver2.tecgraf.puc-rio.br/iup/en/ctrl/iupmatrix.html
+pub struct Matrix(IUPPtr);
+
+impl Matrix {
+    /// Create a new matrix with `rows` rows and `cols` columns of empty cells.
+    pub fn new(rows: usize, cols: usize) -> Matrix {
+        let matrix = unsafe {
+            let ptr = ::iup_sys::IupMatrix(ptr::null());
+            Self::from_ptr(ptr)
+        };
+
+        matrix.set_int_attribute(::attrs::NUM_LIN, rows as i32);
+        matrix.set_int_attribute(::attrs::NUM_COL, cols as i32);
+        matrix
+    }
+
+    /// Get the number of rows in this matrix.
+    pub fn get_row_count(self) -> usize {
+        self.get_int_attribute(::attrs::NUM_LIN) as usize
+    }
+
+    /// Get the number of columns in this matrix.
+    pub fn get_col_count(self) -> usize {
+        self.get_int_attribute(::attrs::NUM_COL) as usize
+    }
+
+    /// Add `count` empty rows after the existing ones.
+    ///
+    /// This can be called after the matrix is shown.
+    pub fn add_rows(self, count: usize) -> Self {
+        self.add_lines_or_cols(::attrs::NUM_LIN, ::attrs::ADD_LIN, count)
+    }
+
+    /// Add `count` empty columns after the existing ones.
+    ///
+    /// This can be called after the matrix is shown.
+    pub fn add_cols(self, count: usize) -> Self {
+        self.add_lines_or_cols(::attrs::NUM_COL, ::attrs::ADD_COL, count)
+    }
+
+    fn add_lines_or_cols(self, num_attr: &'static str, add_attr: &'static str, count: usize)
+    -> Self {
+        if count == 0 {
+            return self;
+        }
+
+        let num = self.get_int_attribute(num_attr);
+
+        // `ADDLIN` and `ADDCOL` only work once the matrix is on-screen.
+        if self.get_str_attribute(::attrs::WID).is_some() {
+            self.set_str_attribute(add_attr, format!("{}-{}", num, count));
+        } else {
+            self.set_int_attribute(num_attr, num + count as i32);
+        }
+
+        self
+    }
+
+    /// Set the text of the cell at `row` and `col`.
+    ///
+    /// Does nothing if the cell is out of bounds.
+    pub fn set_cell(self, row: usize, col: usize, text: &str) -> Self {
+        if row < self.get_row_count() && col < self.get_col_count() {
+            self.set_text_at(row as i32 + 1, col as i32 + 1, text);
+        }
+
+        self
+    }
+
+    /// Get the text of the cell at `row` and `col`.
+    ///
+    /// Returns `None` if the cell is out of bounds. Cells that were never set are empty.
+    pub fn get_cell(&self, row: usize, col: usize) -> Option<String> {
+        if row < self.get_row_count() && col < self.get_col_count() {
+            let text = self.get_str_attribute_id2(::attrs::CELL, row as i32 + 1, col as i32 + 1);
+            Some(text.unwrap_or("").to_owned())
+        } else {
+            None
+        }
+    }
+
+    /// Set the title shown above column `col`.
+    ///
+    /// Does nothing if the column is out of bounds.
+    pub fn set_col_title(self, col: usize, title: &str) -> Self {
+        if col < self.get_col_count() {
+            self.set_text_at(0, col as i32 + 1, title);
+        }
+
+        self
+    }
+
+    /// Set the title shown beside row `row`.
+    ///
+    /// Does nothing if the row is out of bounds.
+    pub fn set_line_title(self, row: usize, title: &str) -> Self {
+        if row < self.get_row_count() {
+            self.set_text_at(row as i32 + 1, 0, title);
+        }
+
+        self
+    }
+
+    // Takes IUP's indices, where 0 is the title row or column.
+    fn set_text_at(self, lin: i32, col: i32, text: &str) {
+        self.set_str_attribute_id2(::attrs::CELL, lin, col, text);
+
+        // Cell changes aren't drawn until requested.
+        if self.get_str_attribute(::attrs::WID).is_some() {
+            self.set_str_attribute(::attrs::REDRAW, format!("L{}", lin));
+        }
+    }
+
+    /// Set if the user is prevented from editing the cells.
+    pub fn set_readonly(self, readonly: bool) -> Self {
+        self.set_bool_attribute(::attrs::READ_ONLY, readonly);
+        self
+    }
+
+    /// Set a callback to be invoked when the user finishes editing a cell, with its row,
+    /// column and new text.
+    ///
+    /// Return `CallbackStatus::Ignore` to reject the new text and keep the old one.
+    pub fn set_on_cell_edited<Cb>(self, on_cell_edited: Cb) -> Self
+    where Cb: Callback<(Self, usize, usize, String)> {
+        callback_impl! {
+            ::attrs::VALUE_EDIT_CB, self, on_cell_edited, Matrix,
+            (lin: ::libc::c_int, col: ::libc::c_int, new_value: *mut ::libc::c_char)
+            -> (Matrix, usize, usize, String),
+            |matrix| if lin > 0 && col > 0 {
+                let text = if new_value.is_null() {
+                    String::new()
+                } else {
+                    unsafe { CStr::from_ptr(new_value) }.to_string_lossy().into_owned()
+                };
+
+                Some((matrix, lin as usize - 1, col as usize - 1, text))
+            } else {
+                None
+            }
+        }
+
+        self
+    }
+}
+
+impl_widget! { Matrix, "matrix" }
+
+impl_on_map! { Matrix }
+
+impl_on_key_press! { Matrix }
+
+impl_on_focus! { Matrix }
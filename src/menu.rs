@@ -0,0 +1,221 @@
+//! Menu bars and their items.
+//!
+//! Use the `children![]` macro in this crate to build the list of items for `Menu::new()`.
+
+use base::BaseWidget;
+use container::raw_handle_vec;
+use widget_prelude::*;
+
+use libc::c_int;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::ptr;
+
+/// A list of menu items, which can be set as the menu bar of a dialog with `Dialog::set_menu()`,
+/// or nested within another menu via `SubMenu`.
+///
+/// For more info, see the [`IupMenu`][iup-menu] documentation.
+///
+/// [iup-menu]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupmenu.html
+pub struct Menu(IUPPtr);
+
+impl Menu {
+    /// Create a new menu with the given vector or array of `MenuItem`, `SubMenu` and `Separator`,
+    /// which may also be empty.
+    ///
+    /// See the `children![]` macro in this crate for more info.
+    pub fn new<C>(items: C) -> Menu where C: AsRef<[BaseWidget]> {
+        let mut raw_handles = raw_handle_vec(items);
+
+        unsafe {
+            let ptr = ::iup_sys::IupMenuv(raw_handles.as_mut_ptr());
+            Self::from_ptr(ptr)
+        }
+    }
+}
+
+impl Destroy for Menu {}
+
+thread_local! {
+    static CONTEXT_MENUS: RefCell<HashMap<IUPPtr, Menu>> = RefCell::new(HashMap::new());
+
+    // The number of widgets each context or tray menu is attached to.
+    static MENU_USERS: RefCell<HashMap<IUPPtr, usize>> = RefCell::new(HashMap::new());
+}
+
+/// Attach `menu` to `widget` as its context menu, releasing the previous one, if any.
+///
+/// The menu is released along with `widget`; see `release_menu()`.
+pub(crate) fn set_context_menu<W: Widget>(widget: W, menu: Menu) {
+    let prev = CONTEXT_MENUS.with(|menus| menus.borrow_mut().insert(widget.ptr(), menu));
+    // Before releasing `prev`, which may be the same menu.
+    retain_menu(menu);
+
+    match prev {
+        Some(prev) => release_menu(prev),
+        None => ::callback::on_destroy(widget, |widget| {
+            if let Some(menu) = CONTEXT_MENUS.with(|menus| menus.borrow_mut().remove(&widget)) {
+                release_menu(menu);
+            }
+        }),
+    }
+
+    ::callback::set_button_callback(widget);
+}
+
+/// Count one more widget that `menu` is attached to.
+pub(crate) fn retain_menu(menu: Menu) {
+    MENU_USERS.with(|users| *users.borrow_mut().entry(menu.ptr()).or_insert(0) += 1);
+}
+
+/// Count one fewer widget that `menu` is attached to, destroying it once there are none left.
+pub(crate) fn release_menu(menu: Menu) {
+    let unused = MENU_USERS.with(|users| {
+        let mut users = users.borrow_mut();
+
+        match users.get_mut(&menu.ptr()) {
+            Some(count) if *count > 1 => {
+                *count -= 1;
+                false
+            },
+            _ => {
+                users.remove(&menu.ptr());
+                true
+            },
+        }
+    });
+
+    if unused {
+        menu.destroy();
+    }
+}
+
+/// Popup the context menu of `element` at the cursor, if it has one and `button` is the right
+/// mouse button being released.
+pub(crate) fn show_context_menu(element: IUPPtr, button: c_int, pressed: bool) {
+    if button != ::iup_sys::IUP_BUTTON3 as c_int || pressed {
+        return;
+    }
+
+    let menu = CONTEXT_MENUS.with(|menus| menus.borrow().get(&element).cloned());
+
+    if let Some(menu) = menu {
+        unsafe {
+            ::iup_sys::IupPopup(menu.ptr(), ::iup_sys::IUP_MOUSEPOS, ::iup_sys::IUP_MOUSEPOS);
+        }
+    }
+}
+
+impl_widget! { Menu, "menu" }
+
+/// An item in a `Menu` which opens another, nested `Menu` when hovered or clicked.
+pub struct SubMenu(IUPPtr);
+
+impl SubMenu {
+    /// Create a new submenu item with the given label, opening `menu`.
+    pub fn new(label: &str, menu: Menu) -> SubMenu {
+        let c_label = CString::new(label).unwrap();
+
+        unsafe {
+            let ptr = ::iup_sys::IupSubmenu(c_label.as_ptr(), menu.ptr());
+            Self::from_ptr(ptr)
+        }
+    }
+}
+
+impl_widget! { SubMenu, "submenu" }
+
+impl Titled for SubMenu {}
+
+/// An item in a `Menu` which can be clicked, and optionally checked.
+pub struct MenuItem(IUPPtr);
+
+impl MenuItem {
+    /// Create a new menu item with the given label.
+    ///
+    /// Prefix a character with `&` to use it as the keyboard mnemonic for the item.
+    pub fn new(label: &str) -> MenuItem {
+        let c_label = CString::new(label).unwrap();
+
+        unsafe {
+            let ptr = ::iup_sys::IupItem(c_label.as_ptr(), ptr::null());
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Set the label of this item, keeping the accelerator shown beside it, if any.
+    pub fn set_label(self, label: &str) -> Self {
+        let accel = self.get_accelerator().map(String::from);
+        self.set_title_parts(label, accel.as_deref())
+    }
+
+    /// Get the label of this item.
+    pub fn get_label(&self) -> &str {
+        self.get_str_attribute(::attrs::TITLE).unwrap_or("").split('\t').next().unwrap_or("")
+    }
+
+    /// Show `accel` beside the label of this item, e.g. `"Ctrl+S"`.
+    ///
+    /// This only displays the accelerator; the key press itself must be handled
+    /// by a key press callback on the dialog.
+    pub fn set_accelerator(self, accel: &str) -> Self {
+        let label = self.get_label().to_owned();
+        self.set_title_parts(&label, Some(accel))
+    }
+
+    /// Get the accelerator shown beside the label of this item, if any.
+    pub fn get_accelerator(&self) -> Option<&str> {
+        self.get_str_attribute(::attrs::TITLE).and_then(|title| title.split('\t').nth(1))
+    }
+
+    fn set_title_parts(self, label: &str, accel: Option<&str>) -> Self {
+        let title = match accel {
+            Some(accel) => format!("{}\t{}", label, accel),
+            None => label.to_owned(),
+        };
+
+        self.set_str_attribute(::attrs::TITLE, title);
+        self
+    }
+
+    /// Set if clicking this item should automatically check or uncheck it.
+    pub fn set_auto_toggle(self, auto_toggle: bool) -> Self {
+        self.set_bool_attribute(::attrs::AUTO_TOGGLE, auto_toggle);
+        self
+    }
+
+    /// Check or uncheck this item. A checked item shows a check mark beside its label.
+    pub fn set_checked(self, checked: bool) -> Self {
+        self.set_bool_attribute(::attrs::VALUE, checked);
+        self
+    }
+
+    /// Returns `true` if this item is checked.
+    pub fn is_checked(&self) -> bool {
+        self.get_bool_attribute(::attrs::VALUE)
+    }
+}
+
+impl_widget! { MenuItem, "item" }
+
+impl_onclick! { MenuItem }
+
+impl_on_action! { MenuItem, ::attrs::ACTION }
+
+impl ::image::ImageContainer for MenuItem {}
+
+/// A line in a `Menu` used to separate groups of items.
+pub struct Separator(IUPPtr);
+
+impl Separator {
+    pub fn new() -> Separator {
+        unsafe {
+            let ptr = ::iup_sys::IupSeparator();
+            Self::from_ptr(ptr)
+        }
+    }
+}
+
+impl_widget! { Separator, "separator" }
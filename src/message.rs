@@ -0,0 +1,139 @@
+//! Modal message dialogs asking the user for a simple response.
+
+use base::BaseWidget;
+use widget_prelude::*;
+
+/// The set of buttons shown by a `MessageDialog`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum MessageButtons {
+    /// A single OK button. **Default.**
+    Ok,
+    OkCancel,
+    YesNo,
+    YesNoCancel,
+}
+
+impl MessageButtons {
+    fn as_cstr(self) -> &'static str {
+        use self::MessageButtons::*;
+
+        match self {
+            Ok => cstr!("OK"),
+            OkCancel => cstr!("OKCANCEL"),
+            YesNo => cstr!("YESNO"),
+            YesNoCancel => cstr!("YESNOCANCEL"),
+        }
+    }
+
+    fn response(self, button: i32) -> MessageResponse {
+        use self::MessageButtons::*;
+
+        match (self, button) {
+            (Ok, 1) | (OkCancel, 1) => MessageResponse::Ok,
+            (OkCancel, 2) | (YesNoCancel, 3) => MessageResponse::Cancel,
+            (YesNo, 1) | (YesNoCancel, 1) => MessageResponse::Yes,
+            (YesNo, 2) | (YesNoCancel, 2) => MessageResponse::No,
+            _ => MessageResponse::Closed,
+        }
+    }
+}
+
+/// The icon shown by a `MessageDialog`, which also determines the system sound played, if any.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum MessageIcon {
+    Info,
+    Warning,
+    Error,
+    Question,
+}
+
+impl MessageIcon {
+    fn as_cstr(self) -> &'static str {
+        use self::MessageIcon::*;
+
+        match self {
+            Info => cstr!("INFORMATION"),
+            Warning => cstr!("WARNING"),
+            Error => cstr!("ERROR"),
+            Question => cstr!("QUESTION"),
+        }
+    }
+}
+
+/// The user's response to a `MessageDialog`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum MessageResponse {
+    Ok,
+    Cancel,
+    Yes,
+    No,
+    /// The dialog was closed without pressing a button.
+    ///
+    /// Most platforms report closing the dialog as pressing the last button (Cancel or No),
+    /// so this is only returned where IUP can tell the two apart.
+    Closed,
+}
+
+/// A builder for a modal message dialog with an icon and a predefined set of buttons.
+///
+/// For more info, see the [`IupMessageDlg`][iup-messagedlg] documentation.
+///
+/// [iup-messagedlg]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iupmessagedlg.html
+pub struct MessageDialog {
+    pub title: String,
+    pub message: String,
+    pub buttons: MessageButtons,
+    pub icon: Option<MessageIcon>,
+}
+
+impl MessageDialog {
+    pub fn new<T: Into<String>, M: Into<String>>(title: T, message: M) -> MessageDialog {
+        MessageDialog {
+            title: title.into(),
+            message: message.into(),
+            buttons: MessageButtons::Ok,
+            icon: None,
+        }
+    }
+
+    /// Set the buttons shown in the dialog.
+    pub fn set_buttons(mut self, buttons: MessageButtons) -> Self {
+        self.buttons = buttons;
+        self
+    }
+
+    /// Set the icon shown in the dialog.
+    pub fn set_icon(mut self, icon: MessageIcon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Popup the dialog and block until the user closes it.
+    ///
+    /// Returns: which button was pressed.
+    ///
+    /// ##Panics
+    /// If called outside a valid KISS-UI context.
+    pub fn popup(self) -> MessageResponse {
+        assert_kiss_running!();
+
+        let dialog = unsafe { BaseWidget::from_ptr(::iup_sys::IupMessageDlg()) };
+
+        dialog.set_str_attribute(::attrs::TITLE, self.title);
+        dialog.set_str_attribute(::attrs::VALUE, self.message);
+        dialog.set_const_str_attribute(::attrs::BUTTONS, self.buttons.as_cstr());
+
+        if let Some(icon) = self.icon {
+            dialog.set_const_str_attribute(::attrs::DIALOG_TYPE, icon.as_cstr());
+        }
+
+        unsafe {
+            ::iup_sys::IupPopup(dialog.ptr(), ::iup_sys::IUP_CENTER, ::iup_sys::IUP_CENTER);
+        }
+
+        let response = dialog.get_int_attribute(::attrs::BUTTON_RESPONSE);
+        unsafe { ::iup_sys::IupDestroy(dialog.ptr()); }
+
+        self.buttons.response(response)
+    }
+}
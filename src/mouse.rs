@@ -0,0 +1,123 @@
+//! Mouse buttons, as reported to mouse callbacks, and mouse cursors.
+
+use key::KeyMods;
+
+use std::ffi::CStr;
+
+/// A button on the mouse.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum MouseButton {
+    Left,
+    Middle,
+    Right,
+    /// The first extra button, usually "Back".
+    X1,
+    /// The second extra button, usually "Forward".
+    X2,
+}
+
+impl MouseButton {
+    /// Convert an IUP button code, as passed to `BUTTON_CB`, to a `MouseButton`.
+    #[doc(hidden)]
+    pub fn from_code(code: i32) -> Option<MouseButton> {
+        use self::MouseButton::*;
+
+        match code as u8 {
+            b'1' => Some(Left),
+            b'2' => Some(Middle),
+            b'3' => Some(Right),
+            b'4' => Some(X1),
+            b'5' => Some(X2),
+            _ => None,
+        }
+    }
+}
+
+/// The state of the mouse and modifier keys, parsed from the status string IUP passes to mouse
+/// callbacks.
+#[doc(hidden)]
+pub struct MouseStatus {
+    pub mods: KeyMods,
+    pub double_click: bool,
+}
+
+impl MouseStatus {
+    #[doc(hidden)]
+    pub unsafe fn from_ptr(status: *const ::libc::c_char) -> MouseStatus {
+        // A string like "SC123DAY45", where each character is replaced by a space if the
+        // corresponding key or button is not held.
+        let status = if status.is_null() { &[][..] } else { CStr::from_ptr(status).to_bytes() };
+        let has = |idx: usize, flag: u8| status.get(idx) == Some(&flag);
+
+        MouseStatus {
+            mods: KeyMods {
+                shift: has(0, b'S'),
+                ctrl: has(1, b'C'),
+                alt: has(6, b'A'),
+                sys: has(7, b'Y'),
+            },
+            double_click: has(5, b'D'),
+        }
+    }
+}
+
+/// A standard mouse cursor, drawn by the platform.
+///
+/// Set on a widget with `Widget::set_cursor()`. Some cursors fall back to `Arrow` on platforms
+/// without an equivalent.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Cursor {
+    /// The normal pointer. **Default.**
+    Arrow,
+    /// A pointing hand, as used for links.
+    Hand,
+    Cross,
+    /// The I-beam shown over editable text.
+    Text,
+    /// The platform's "wait" cursor, e.g. an hourglass.
+    Busy,
+    /// An arrow with a small "wait" indicator, while the application is starting up or busy in
+    /// the background.
+    AppStarting,
+    /// An arrow with a question mark.
+    Help,
+    /// Arrows pointing in all four directions.
+    Move,
+    Pen,
+    /// A vertical double arrow, for resizing up or down.
+    ResizeNS,
+    /// A horizontal double arrow, for resizing left or right.
+    ResizeWE,
+    /// A diagonal double arrow, from the top-left to the bottom-right.
+    ResizeNWSE,
+    /// A diagonal double arrow, from the top-right to the bottom-left.
+    ResizeNESW,
+    UpArrow,
+    /// No cursor at all.
+    Hidden,
+}
+
+impl Cursor {
+    #[doc(hidden)]
+    pub fn as_cstr(self) -> &'static str {
+        use self::Cursor::*;
+
+        match self {
+            Arrow => cstr!("ARROW"),
+            Hand => cstr!("HAND"),
+            Cross => cstr!("CROSS"),
+            Text => cstr!("TEXT"),
+            Busy => cstr!("BUSY"),
+            AppStarting => cstr!("APPSTARTING"),
+            Help => cstr!("HELP"),
+            Move => cstr!("MOVE"),
+            Pen => cstr!("PEN"),
+            ResizeNS => cstr!("RESIZE_NS"),
+            ResizeWE => cstr!("RESIZE_WE"),
+            ResizeNWSE => cstr!("RESIZE_NW"),
+            ResizeNESW => cstr!("RESIZE_NE"),
+            UpArrow => cstr!("UPARROW"),
+            Hidden => cstr!("NONE"),
+        }
+    }
+}
@@ -7,7 +7,7 @@ use container::Orientation;
 /// A widget that renders a bar which fills as its set value approaches a maximum.
 ///
 /// For more info, see the [`IupProgressBar`][iup-progress] documentation. (Note: "marquee" is the
-/// same as "indefinite")
+/// same as "indeterminate")
 ///
 /// [iup-progress]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupprogressbar.html
 pub struct ProgressBar(IUPPtr);
@@ -21,17 +21,38 @@ impl ProgressBar {
         }
     }
 
-    /// Set this progress bar as indefinite or not. 
+    /// Set this progress bar as indeterminate or not, for showing activity when the total amount
+    /// of work is unknown.
     ///
-    /// In the indefinite state, the progress bar will not
-    /// show its true value; instead it will render a looping animation.
+    /// In the indeterminate state, the progress bar will not show its true value; instead it will
+    /// render a looping animation. `set_value()` and `add_value()` are ignored, and `get_value()`
+    /// returns `None`. When switched back, the value it had before is restored.
     ///
     /// This may not have a visual effect on certain platforms. 
-    pub fn set_indefinite(self, is_indefinite: bool) -> Self {
-        self.set_bool_attribute(::attrs::MARQUEE, is_indefinite);
+    pub fn set_indeterminate(self, indeterminate: bool) -> Self {
+        if indeterminate == self.is_indeterminate() {
+            return self;
+        }
+
+        if indeterminate {
+            let val = self.get_double_attribute(::attrs::VALUE);
+            self.set_double_attribute(::attrs::KISS_VALUE, val);
+            self.set_bool_attribute(::attrs::MARQUEE, true);
+        } else {
+            self.set_bool_attribute(::attrs::MARQUEE, false);
+            // Clamped, in case the range changed in the meantime.
+            let val = self.get_double_attribute(::attrs::KISS_VALUE);
+            self.set_value(val);
+        }
+
         self
     }
 
+    /// Check if this progress bar is in the indeterminate state.
+    pub fn is_indeterminate(&self) -> bool {
+        self.get_bool_attribute(::attrs::MARQUEE)
+    }
+
     /// Set if the progress bar should render solid (`false`) or dashed (`true`).
     ///
     /// This may not have a visual effect on certain platforms.
@@ -42,18 +63,61 @@ impl ProgressBar {
 
     /// Set the maximum value of this progress bar, i.e. the value at which it will show full.
     ///
-    /// Defaults to `1.0`.
-    pub fn set_max(self, max: f32) -> Self {
-        self.set_float_attribute(::attrs::MAX, max);
-        self
+    /// Defaults to `1.0`. If `max` is less than the minimum, the minimum is lowered to `max` as
+    /// well, so the range is never inverted. The current value is clamped to the new range.
+    pub fn set_max(self, max: f64) -> Self {
+        let (min, _) = self.get_range();
+        self.set_range(min.min(max), max)
     }
 
     /// Set the minimum value of this progress bar, i.e. the value at which it will be empty.
     ///
-    /// Defaults to `0.0`.
-    pub fn set_min(self, min: f32) -> Self {
-        self.set_float_attribute(::attrs::MIN, min);
-        self
+    /// Defaults to `0.0`. If `min` is greater than the maximum, the maximum is raised to `min` as
+    /// well, so the range is never inverted. The current value is clamped to the new range.
+    pub fn set_min(self, min: f64) -> Self {
+        let (_, max) = self.get_range();
+        self.set_range(min, max.max(min))
+    }
+
+    /// Set the minimum and maximum value of this progress bar in one call.
+    ///
+    /// The current value is clamped to the new range. If `min == max`, the progress bar is
+    /// always shown full: any value set afterwards is stored as `max`, and `get_percent()`
+    /// returns `100.0`.
+    ///
+    /// ##Panics
+    /// If `min > max`.
+    pub fn set_range(self, min: f64, max: f64) -> Self {
+        assert!(min <= max, "`min` ({}) was greater than `max` ({})", min, max);
+
+        self.apply_range(min, max);
+
+        let val = self.get_double_attribute(::attrs::VALUE);
+        self.set_value(val)
+    }
+
+    /// Get the minimum and maximum value of this progress bar, as last set.
+    pub fn get_range(self) -> (f64, f64) {
+        // Set by `apply_range()`, which may have given IUP a different minimum.
+        if self.get_str_attribute(::attrs::KISS_MIN).is_some() {
+            let min = self.get_double_attribute(::attrs::KISS_MIN);
+            (min, self.get_double_attribute(::attrs::KISS_MAX))
+        } else {
+            (self.get_double_attribute(::attrs::MIN), self.get_double_attribute(::attrs::MAX))
+        }
+    }
+
+    /// Store the range `min` to `max` and pass it on to IUP.
+    fn apply_range(self, min: f64, max: f64) {
+        self.set_double_attribute(::attrs::KISS_MIN, min);
+        self.set_double_attribute(::attrs::KISS_MAX, max);
+
+        // IUP divides by the width of the range, so give it a nonzero one; `set_value()` keeps
+        // the value pinned to the maximum.
+        let iup_min = if min == max { min - 1.0 } else { min };
+
+        self.set_double_attribute(::attrs::MIN, iup_min);
+        self.set_double_attribute(::attrs::MAX, max);
     }
 
     /// Set the orientation of this progress bar.
@@ -68,23 +132,134 @@ impl ProgressBar {
 
     /// Set the current value of this progress bar. Its rendered infill will be updated to reflect
     /// the new value in relation to the minimum and maximum.
-    pub fn set_value(self, val: f32) -> Self {
-        self.set_float_attribute(::attrs::VALUE, val);
+    ///
+    /// The value is clamped to the range of this progress bar, or set to its maximum if the
+    /// range is empty.
+    ///
+    /// Ignored if this progress bar is indeterminate.
+    pub fn set_value(self, val: f64) -> Self {
+        if !self.is_indeterminate() {
+            let (min, max) = self.get_range();
+            let val = if min == max { max } else { val.max(min).min(max) };
+            self.set_double_attribute(::attrs::VALUE, val);
+        }
+
         self
     }
+
+    /// Set the current value as a percentage from `0.0` to `100.0` of the range of this
+    /// progress bar.
+    ///
+    /// Ignored if this progress bar is indeterminate.
+    pub fn set_percent(self, pct: f64) -> Self {
+        let (min, max) = self.get_range();
+        self.set_value(min + (max - min) * pct / 100.0)
+    }
+
+    /// Get the current value as a percentage from `0.0` to `100.0` of the range of this
+    /// progress bar.
+    ///
+    /// Returns `100.0` if the range is empty, and `0.0` if this progress bar is indeterminate.
+    pub fn get_percent(self) -> f64 {
+        let (min, max) = self.get_range();
+
+        match self.get_value() {
+            Some(_) if max <= min => 100.0,
+            Some(val) => (val - min) / (max - min) * 100.0,
+            None => 0.0,
+        }
+    }
     
-    /// Get the current value.
-    pub fn get_value(self) -> f32 {
-        self.get_float_attribute(::attrs::VALUE)
+    /// Get the current value, or `None` if this progress bar is indeterminate.
+    pub fn get_value(self) -> Option<f64> {
+        if self.is_indeterminate() {
+            None
+        } else {
+            Some(self.get_double_attribute(::attrs::VALUE))
+        }
     }
 
     /// Add `amt` to the current value and update it. `amt` may be negative. 
-    pub fn add_value(self, amt: f32) -> Self {
-        let val = self.get_float_attribute(::attrs::VALUE);
-        self.set_float_attribute(::attrs::VALUE, val + amt);
+    ///
+    /// Ignored if this progress bar is indeterminate.
+    pub fn add_value(self, amt: f64) -> Self {
+        if let Some(val) = self.get_value() {
+            self.set_value(val + amt);
+        }
+
         self
     }
 }
 
 impl_widget! { ProgressBar, "progressbar" }
 
+impl_on_map! { ProgressBar }
+
+
+/// A dialog showing a progress bar, a description of the current work and a Cancel button, for
+/// long operations.
+///
+/// Show it with `Widget::show()` and update it as the work progresses, e.g. from a callback set
+/// with `kiss_ui::set_idle()`. Check `was_cancelled()` to know when to stop, then `destroy()` it.
+///
+/// For more info, see the [`IupProgressDlg`][iup-progressdlg] documentation.
+///
+/// [iup-progressdlg]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iupprogressdlg.html
+pub struct ProgressDialog(IUPPtr);
+
+impl ProgressDialog {
+    /// Create a new progress dialog with a total count of 100.
+    ///
+    /// ##Panics
+    /// If called outside a valid KISS-UI context.
+    pub fn new() -> ProgressDialog {
+        assert_kiss_running!();
+
+        unsafe {
+            let ptr = ::iup_sys::IupProgressDlg();
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Set the text shown above the progress bar.
+    pub fn set_description(self, description: &str) -> Self {
+        self.set_str_attribute(::attrs::DESCRIPTION, description);
+        self
+    }
+
+    /// Set the count at which the work is complete and the progress bar is full.
+    pub fn set_total_count(self, total: u32) -> Self {
+        self.set_int_attribute(::attrs::TOTAL_COUNT, total as i32);
+        self
+    }
+
+    /// Get the count at which the work is complete.
+    pub fn get_total_count(self) -> u32 {
+        self.get_int_attribute(::attrs::TOTAL_COUNT) as u32
+    }
+
+    /// Set the amount of work done, clamped to the total count.
+    pub fn set_count(self, count: u32) -> Self {
+        let count = count.min(self.get_total_count());
+        self.set_int_attribute(::attrs::COUNT, count as i32);
+        self
+    }
+
+    /// Get the amount of work done.
+    pub fn get_count(self) -> u32 {
+        self.get_int_attribute(::attrs::COUNT) as u32
+    }
+
+    /// Check if the user pressed the Cancel button.
+    ///
+    /// The dialog stays open afterwards, so it can show that the work is being cancelled.
+    pub fn was_cancelled(self) -> bool {
+        self.get_str_attribute(::attrs::STATE) == Some("ABORTED")
+    }
+}
+
+impl Destroy for ProgressDialog {}
+
+impl Titled for ProgressDialog {}
+
+impl_widget! { ProgressDialog, "progressdlg" }
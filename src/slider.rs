@@ -0,0 +1,166 @@
+//! Sliders for selecting a value within a range.
+
+use callback::{Callback, CallbackMap};
+use container::Orientation;
+use utils::cstr::AsCStr;
+use widget_prelude::*;
+
+use iup_sys::CallbackReturn;
+use libc::c_int;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::mem;
+
+thread_local! {
+    static VALUE_CHANGED_CALLBACKS: CallbackMap<(Slider, f64)> = RefCell::new(HashMap::new());
+}
+
+/// A widget with a handle that the user can drag along a track to select a value within a range.
+///
+/// The default range is `0.0 ..= 1.0`.
+///
+/// For more info, see the [`IupVal`][iup-val] documentation.
+///
+/// [iup-val]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupval.html
+pub struct Slider(IUPPtr);
+
+impl Slider {
+    /// Create a new slider with the given orientation.
+    ///
+    /// * `Vertical`: The minimum value is at the bottom of the track.
+    /// * `Horizontal`: The minimum value is at the left of the track.
+    pub fn new(orientation: Orientation) -> Slider {
+        unsafe {
+            let ptr = ::iup_sys::IupVal(orientation.as_cstr().as_cstr());
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Set the minimum and maximum value of this slider, inclusive.
+    ///
+    /// The current value is clamped to the new range.
+    ///
+    /// ##Panics
+    /// If `min >= max`.
+    pub fn set_range(self, min: f64, max: f64) -> Self {
+        assert!(min < max, "`min` ({}) was not less than `max` ({})", min, max);
+
+        self.set_float_attribute(::attrs::MIN, min as f32);
+        self.set_float_attribute(::attrs::MAX, max as f32);
+
+        let value = self.get_value();
+        self.set_value(value)
+    }
+
+    /// Get the minimum and maximum value of this slider, inclusive.
+    pub fn get_range(self) -> (f64, f64) {
+        (
+            self.get_float_attribute(::attrs::MIN) as f64,
+            self.get_float_attribute(::attrs::MAX) as f64,
+        )
+    }
+
+    /// Set the value of this slider, clamped to its range.
+    pub fn set_value(self, value: f64) -> Self {
+        let (min, max) = self.get_range();
+        self.set_float_attribute(::attrs::VALUE, value.max(min).min(max) as f32);
+        self
+    }
+
+    /// Get the current value of this slider.
+    pub fn get_value(self) -> f64 {
+        self.get_float_attribute(::attrs::VALUE) as f64
+    }
+
+    /// Set how much the value changes with the arrow keys or the mouse wheel.
+    ///
+    /// IUP stores this relative to the range, so it should be set after `set_range()`.
+    pub fn set_step(self, step: f64) -> Self {
+        self.set_relative_step(::attrs::STEP, step)
+    }
+
+    /// Set how much the value changes with the Page Up/Page Down keys, or when clicking the track.
+    ///
+    /// IUP stores this relative to the range, so it should be set after `set_range()`.
+    pub fn set_page_step(self, page_step: f64) -> Self {
+        self.set_relative_step(::attrs::PAGE_STEP, page_step)
+    }
+
+    fn set_relative_step(self, name: &'static str, step: f64) -> Self {
+        let (min, max) = self.get_range();
+        self.set_float_attribute(name, (step / (max - min)) as f32);
+        self
+    }
+
+    /// Set if the value changed callback should be invoked continuously while the user drags
+    /// the handle (`true`), or only once when the handle is released (`false`).
+    /// **Default: `true`.**
+    ///
+    /// Changes made with the keyboard or the mouse wheel are always reported right away. This
+    /// can be changed at any time, even while the user is dragging the handle.
+    pub fn set_continuous(self, continuous: bool) -> Self {
+        self.set_bool_attribute(::attrs::KISS_CONTINUOUS, continuous);
+        self
+    }
+
+    fn is_continuous(self) -> bool {
+        self.get_str_attribute(::attrs::KISS_CONTINUOUS) != Some("NO")
+    }
+
+    /// Set a callback to be invoked when the user changes the value of this slider,
+    /// with the new value.
+    ///
+    /// See `set_continuous()` for when it is invoked while dragging.
+    pub fn set_on_value_changed<Cb>(self, on_value_changed: Cb) -> Self
+    where Cb: Callback<(Self, f64)> {
+        ::callback::store_callback(&VALUE_CHANGED_CALLBACKS, self, Box::new(on_value_changed));
+
+        let value_changing = unsafe {
+            mem::transmute::<
+                extern "C" fn(IUPPtr, c_int) -> CallbackReturn,
+                ::iup_sys::Icallback
+            >(value_changing_callback)
+        };
+
+        self.set_callback(::attrs::VALUE_CHANGED_CB, value_changed_callback);
+        self.set_callback(::attrs::VALUE_CHANGING_CB, value_changing);
+        self
+    }
+
+    fn report_value(self) -> CallbackReturn {
+        let args = (self, self.get_value());
+        ::callback::invoke_callback(&VALUE_CHANGED_CALLBACKS, self.ptr(), args).to_cb_return()
+    }
+}
+
+extern "C" fn value_changed_callback(element: IUPPtr) -> CallbackReturn {
+    let slider = unsafe { Slider::from_ptr(element) };
+
+    // Decided here rather than when the callback is set, so `set_continuous()` applies at once.
+    if slider.get_bool_attribute(::attrs::KISS_DRAGGING) && !slider.is_continuous() {
+        slider.set_bool_attribute(::attrs::KISS_DRAG_PENDING, true);
+        return CallbackReturn::Default;
+    }
+
+    slider.report_value()
+}
+
+// Invoked with `start` as `1` when the user starts dragging the handle, and `0` when they stop.
+extern "C" fn value_changing_callback(element: IUPPtr, start: c_int) -> CallbackReturn {
+    let slider = unsafe { Slider::from_ptr(element) };
+    slider.set_bool_attribute(::attrs::KISS_DRAGGING, start != 0);
+
+    if start == 0 && slider.get_bool_attribute(::attrs::KISS_DRAG_PENDING) {
+        slider.set_bool_attribute(::attrs::KISS_DRAG_PENDING, false);
+        return slider.report_value();
+    }
+
+    CallbackReturn::Default
+}
+
+impl_widget! { Slider, "val" }
+
+impl_on_map! { Slider }
+
+impl_on_key_press! { Slider }
@@ -0,0 +1,142 @@
+//! Numeric input with up/down arrows.
+
+use widget_prelude::*;
+
+use ::callback::Callback;
+
+use std::cmp;
+use std::ptr;
+
+/// A text box that only accepts integers, with arrows for incrementing and decrementing the value.
+///
+/// The default range is `0 ..= 100`, with a step of `1`.
+///
+/// For more info, see the `SPIN` attributes in the [`IupText`][iup-text] documentation.
+///
+/// [iup-text]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iuptext.html
+pub struct Spin(IUPPtr);
+
+impl Spin {
+    /// Create a new spin box with the default range and a value of `0`.
+    pub fn new() -> Spin {
+        unsafe {
+            let ptr = ::iup_sys::IupText(ptr::null());
+            let spin = Self::from_ptr(ptr);
+            spin.set_bool_attribute(::attrs::SPIN, true);
+            spin
+        }
+    }
+
+    /// Set the value of this spin box, clamped to its range.
+    pub fn set_value(self, value: i32) -> Self {
+        let (min, max) = self.get_range();
+        let value = cmp::max(min, cmp::min(value, max));
+        self.set_int_attribute(::attrs::SPIN_VALUE, value);
+        self.set_int_attribute(::attrs::KISS_SPIN_LAST, value);
+        self
+    }
+
+    /// Get the current value of this spin box.
+    pub fn get_value(self) -> i32 {
+        self.get_int_attribute(::attrs::SPIN_VALUE)
+    }
+
+    /// Set the minimum and maximum value of this spin box, inclusive.
+    ///
+    /// The current value is clamped to the new range.
+    ///
+    /// ##Panics
+    /// If `min > max`.
+    pub fn set_range(self, min: i32, max: i32) -> Self {
+        assert!(min <= max, "`min` ({}) was greater than `max` ({})", min, max);
+
+        self.set_int_attribute(::attrs::SPIN_MIN, min);
+        self.set_int_attribute(::attrs::SPIN_MAX, max);
+
+        let value = self.get_value();
+        self.set_value(value)
+    }
+
+    /// Get the minimum and maximum value of this spin box, inclusive.
+    pub fn get_range(self) -> (i32, i32) {
+        (self.get_int_attribute(::attrs::SPIN_MIN), self.get_int_attribute(::attrs::SPIN_MAX))
+    }
+
+    /// Set how much the value changes when an arrow is clicked.
+    pub fn set_step(self, step: i32) -> Self {
+        self.set_int_attribute(::attrs::SPIN_INC, step);
+        self
+    }
+
+    /// Set if incrementing past the maximum should wrap around to the minimum, and vice versa.
+    pub fn set_wrap(self, wrap: bool) -> Self {
+        self.set_bool_attribute(::attrs::SPIN_WRAP, wrap);
+        self
+    }
+
+    /// Set a callback to be invoked when the value is stepped with the arrows or arrow keys,
+    /// with the direction of the step.
+    ///
+    /// While an arrow is held down, this is invoked for every repeated step. It is invoked in
+    /// addition to the `OnValueChange::set_on_value_changed()` callback.
+    pub fn set_on_spin<Cb>(self, on_spin: Cb) -> Self where Cb: Callback<(Self, SpinDirection)> {
+        let value = self.get_value();
+        self.set_int_attribute(::attrs::KISS_SPIN_LAST, value);
+
+        callback_impl! {
+            ::attrs::SPIN_CB, self, on_spin, Spin,
+            (pos: ::libc::c_int) -> (Spin, SpinDirection),
+            |spin| spin.step_direction(pos).map(|direction| (spin, direction))
+        }
+
+        self
+    }
+
+    // IUP only passes the new value to `SPIN_CB`, so the direction is found by comparing it
+    // with the previous one. Depending on the platform, the text is updated either before or
+    // after the callback, so it is used as the previous value if it differs.
+    fn step_direction(self, pos: i32) -> Option<SpinDirection> {
+        let text = self.get_str_attribute(::attrs::VALUE);
+        let last = match text.and_then(|text| text.trim().parse().ok()) {
+            Some(typed) if typed != pos => typed,
+            _ => self.get_int_attribute(::attrs::KISS_SPIN_LAST),
+        };
+
+        self.set_int_attribute(::attrs::KISS_SPIN_LAST, pos);
+
+        let (min, max) = self.get_range();
+        let step = self.get_int_attribute(::attrs::SPIN_INC);
+        let wrapped = self.get_bool_attribute(::attrs::SPIN_WRAP) && (pos - last).abs() != step;
+
+        match pos.cmp(&last) {
+            // Wrapping around from one end of the range to the other.
+            cmp::Ordering::Greater if wrapped && last == min && pos == max =>
+                Some(SpinDirection::Down),
+            cmp::Ordering::Less if wrapped && last == max && pos == min =>
+                Some(SpinDirection::Up),
+            cmp::Ordering::Greater => Some(SpinDirection::Up),
+            cmp::Ordering::Less => Some(SpinDirection::Down),
+            cmp::Ordering::Equal => None,
+        }
+    }
+}
+
+/// The direction of a step of a `Spin`, as passed to `Spin::set_on_spin()`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SpinDirection {
+    /// The value was incremented.
+    Up,
+    /// The value was decremented.
+    Down,
+}
+
+// An `IupText` with `SPIN` set; see `TextBox`.
+impl_widget! { Spin, "text", |widget| widget.get_bool_attribute(::attrs::SPIN) }
+
+impl_on_map! { Spin }
+
+impl_on_value_change! { Spin }
+
+impl_on_key_press! { Spin }
+
+impl_on_focus! { Spin }
@@ -0,0 +1,90 @@
+//! Application state shared between callbacks without `Rc<RefCell<_>>` boilerplate.
+//!
+//! Store one value with `set_app_state()`, then borrow it mutably from any callback with
+//! `app_state::<T>()`:
+//!
+//! ```ignore
+//! struct App { clicks: u32 }
+//!
+//! kiss_ui::set_app_state(App { clicks: 0 });
+//!
+//! let on_click = |_| kiss_ui::app_state::<App>().clicks += 1;
+//! ```
+//!
+//! The state is per-thread, so it is only reachable from the thread running `show_gui()`.
+
+use std::any::{self, Any};
+use std::cell::{RefCell, RefMut};
+
+type StateCell = RefCell<Option<Box<dyn Any>>>;
+
+thread_local! {
+    // Leaked once per thread so that borrows of it can be handed out as `'static`.
+    static APP_STATE: &'static StateCell = Box::leak(Box::new(RefCell::new(None)));
+}
+
+fn state_cell() -> &'static StateCell {
+    APP_STATE.with(|state| *state)
+}
+
+/// Store `state` as the application state, replacing any previous value of any type.
+///
+/// The state is kept until it is replaced or `clear_app_state()` is called; it may be set
+/// before `show_gui()` is invoked and read after it returns.
+///
+/// ##Panics
+/// If the application state is currently borrowed through `app_state()`.
+pub fn set_app_state<T: 'static>(state: T) {
+    let old = {
+        let mut slot = borrow_state("set_app_state()");
+        slot.replace(Box::new(state))
+    };
+
+    // Drop the previous state outside of the borrow, in case its destructor touches the state.
+    drop(old);
+}
+
+/// Remove and drop the application state, if any was set.
+///
+/// ##Panics
+/// If the application state is currently borrowed through `app_state()`.
+pub fn clear_app_state() {
+    let old = borrow_state("clear_app_state()").take();
+    drop(old);
+}
+
+/// Mutably borrow the application state previously stored with `set_app_state()`.
+///
+/// The borrow is checked at runtime and is released when the returned guard is dropped. Keep
+/// it short-lived: don't hold it across calls that may run other callbacks, like `popup()`.
+///
+/// ##Panics
+/// * If the application state is already borrowed, e.g. because a callback that holds the
+///   borrow caused another callback to run which also called `app_state()`.
+/// * If no application state was set or it is not of type `T`.
+pub fn app_state<T: 'static>() -> RefMut<'static, T> {
+    let slot = borrow_state("app_state()");
+
+    if !slot.as_ref().is_some_and(|state| state.is::<T>()) {
+        let found = if slot.is_some() { "a value of another type" } else { "no value" };
+
+        drop(slot);
+
+        panic!(
+            "app_state::<{}>() called, but the application state holds {}; call \
+             `kiss_ui::set_app_state()` with a value of that type first",
+            any::type_name::<T>(), found
+        );
+    }
+
+    RefMut::map(slot, |state| state.as_mut().and_then(|state| state.downcast_mut()).unwrap())
+}
+
+fn borrow_state(caller: &str) -> RefMut<'static, Option<Box<dyn Any>>> {
+    state_cell().try_borrow_mut().unwrap_or_else(|_| panic!(
+        "{} called while the application state is already borrowed; drop the value returned \
+         by `kiss_ui::app_state()` before anything that can call back into it, such as \
+         reentrant callbacks",
+        caller
+    ))
+}
@@ -0,0 +1,114 @@
+//! Status bars showing short texts in sections along the bottom of a dialog.
+
+use base::BaseWidget;
+use container::{Orientation, VAlign};
+use label::Label;
+use widget::Expand;
+use widget_prelude::*;
+
+/// The width of a section of a `StatusBar`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SectionWidth {
+    /// A fixed width, in pixels. Text that does not fit is clipped.
+    Fixed(u32),
+    /// Share the width left over by the fixed sections with the other `Fill` sections.
+    Fill,
+}
+
+/// A horizontal strip of text sections divided by separator lines, e.g. to show the state of an
+/// application below its main content.
+///
+/// Put it last in a `Vertical` that fills the dialog. It expands horizontally but never
+/// vertically, so it stays at the bottom, one line high.
+///
+/// Sections are addressed by their zero-based index, from left to right. Their widths do not
+/// depend on their texts, so changing the text of one section never moves the others.
+pub struct StatusBar(IUPPtr);
+
+impl StatusBar {
+    /// Create a status bar with one empty section for each entry of `widths`.
+    pub fn new(widths: &[SectionWidth]) -> StatusBar {
+        let mut sections = Vec::with_capacity(widths.len() * 2);
+
+        for (idx, &width) in widths.iter().enumerate() {
+            if idx > 0 {
+                sections.push(Label::separator(Orientation::Vertical).to_base());
+            }
+
+            sections.push(set_section_width(Label::new_empty(), width).to_base());
+        }
+
+        let mut raw_handles = ::container::raw_handle_vec(sections);
+
+        let status_bar = unsafe {
+            let ptr = ::iup_sys::IupHboxv(raw_handles.as_mut_ptr());
+            Self::from_ptr(ptr)
+        };
+
+        // Tells a status bar apart from other `IupHbox`es when casting.
+        status_bar.set_bool_attribute(::attrs::KISS_STATUSBAR, true);
+        status_bar.set_const_str_attribute(::attrs::ALIGNMENT, VAlign::Center.as_cstr());
+        status_bar.set_str_attribute(::attrs::MARGIN, "4x2");
+        status_bar.set_expand(Expand::Horizontal)
+    }
+
+    /// Set the text of the section at `section`.
+    ///
+    /// Does nothing if `section` is out of range.
+    pub fn set_text(self, section: usize, text: &str) -> Self {
+        // Unlike `Label::set_text()`, the layout is not refreshed, since the size of the section
+        // does not depend on its text.
+        if let Some(label) = self.section(section) {
+            label.set_str_attribute(::attrs::TITLE, text);
+        }
+
+        self
+    }
+
+    /// Get the text of the section at `section`, if it exists.
+    pub fn get_text(&self, section: usize) -> Option<String> {
+        self.section(section)
+            .map(|label| label.get_str_attribute(::attrs::TITLE).unwrap_or("").to_owned())
+    }
+
+    /// Set the width of the section at `section`.
+    ///
+    /// This can be changed after the status bar is shown. Does nothing if `section` is out of
+    /// range.
+    pub fn set_section_width(self, section: usize, width: SectionWidth) -> Self {
+        if let Some(label) = self.section(section) {
+            set_section_width(label, width);
+            ::container::refresh_if_mapped(self);
+        }
+
+        self
+    }
+
+    /// Get the number of sections in this status bar.
+    pub fn get_section_count(&self) -> usize {
+        let children = unsafe { ::iup_sys::IupGetChildCount(self.ptr()) as usize };
+        // Every section but the first is preceded by a separator.
+        children.div_ceil(2)
+    }
+
+    fn section(&self, section: usize) -> Option<BaseWidget> {
+        if section >= self.get_section_count() {
+            return None;
+        }
+
+        unsafe {
+            let ptr = ::iup_sys::IupGetChild(self.ptr(), (section * 2) as ::libc::c_int);
+            BaseWidget::from_ptr_opt(ptr)
+        }
+    }
+}
+
+fn set_section_width<W>(label: W, width: SectionWidth) -> W where W: Widget {
+    match width {
+        SectionWidth::Fixed(width) => label.set_size_pixels(width, 0).set_expand(Expand::No),
+        // A minimal natural width, so the text never takes space from other sections.
+        SectionWidth::Fill => label.set_size_pixels(1, 0).set_expand(Expand::Horizontal),
+    }
+}
+
+impl_widget! { StatusBar, "hbox", |widget| widget.get_bool_attribute(::attrs::KISS_STATUSBAR) }
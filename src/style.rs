@@ -0,0 +1,135 @@
+//! App-wide default fonts and colors.
+
+use utils::cstr::AsCStr;
+
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+
+// The globals set by `set_theme()`.
+const THEME_GLOBALS: [&str; 5] = [
+    ::attrs::DLG_BG_COLOR, ::attrs::DLG_FG_COLOR,
+    ::attrs::TXT_BG_COLOR, ::attrs::TXT_FG_COLOR, ::attrs::TXT_HL_COLOR,
+];
+
+thread_local! {
+    // The values of `THEME_GLOBALS` before the first call to `set_theme()`, which IUP derives
+    // from the system theme.
+    static SYSTEM_COLORS: RefCell<Option<Vec<Option<String>>>> = const { RefCell::new(None) };
+}
+
+/// A coherent set of colors for dialogs, text and selections, applied with `set_theme()`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Theme {
+    /// Dark text on light backgrounds.
+    Light,
+    /// Light text on dark backgrounds.
+    Dark,
+    /// The colors of the system theme, which IUP uses by default.
+    System,
+}
+
+impl Theme {
+    /// Check if the system is set to use dark colors.
+    ///
+    /// Only Windows reports this; elsewhere, this is always `false`, but the native widgets
+    /// follow the system theme with `Theme::System` anyway.
+    ///
+    /// ##Panics
+    /// If called outside a valid KISS-UI context.
+    pub fn system_prefers_dark() -> bool {
+        assert_kiss_running!();
+        get_global(::attrs::DARK_MODE).is_some_and(|dark| dark == "YES")
+    }
+
+    // In the order of `THEME_GLOBALS`, or `None` for the system colors.
+    fn colors(self) -> Option<[(u8, u8, u8); 5]> {
+        match self {
+            Theme::Light => Some([
+                (240, 240, 240), (0, 0, 0), (255, 255, 255), (0, 0, 0), (51, 153, 255),
+            ]),
+            Theme::Dark => Some([
+                (45, 45, 48), (230, 230, 230), (30, 30, 30), (230, 230, 230), (38, 79, 120),
+            ]),
+            Theme::System => None,
+        }
+    }
+}
+
+/// Set the default background, text and selection colors of all widgets to those of `theme`.
+///
+/// ##Note
+/// Only widgets created afterwards use the new colors, so this is best called at the start of
+/// the closure passed to `show_gui()`. Widgets that have already been shown keep their colors,
+/// and colors set on a widget itself take precedence, as with `set_global_bg_color()`.
+///
+/// ##Panics
+/// If called outside a valid KISS-UI context.
+pub fn set_theme(theme: Theme) {
+    assert_kiss_running!();
+
+    SYSTEM_COLORS.with(|system| {
+        let mut system = system.borrow_mut();
+
+        if system.is_none() {
+            *system = Some(THEME_GLOBALS.iter().map(|&name| get_global(name)).collect());
+        }
+
+        match theme.colors() {
+            Some(colors) => for (&name, &color) in THEME_GLOBALS.iter().zip(&colors) {
+                set_global_color(name, color);
+            },
+            None => for (&name, color) in THEME_GLOBALS.iter().zip(system.as_ref().unwrap()) {
+                if let Some(ref color) = *color {
+                    set_global(name, color);
+                }
+            },
+        }
+    });
+}
+
+/// Set the default font of all widgets, in IUP's `"<face>, <styles> <size>"` format, e.g.
+/// `"Helvetica, 10"`. See `Widget::set_font()`.
+///
+/// Only widgets created afterwards use the new font, so this is best called at the start of
+/// the closure passed to `show_gui()`. A font set on a widget itself takes precedence.
+///
+/// ##Panics
+/// If called outside a valid KISS-UI context.
+pub fn set_global_font(font: &str) {
+    set_global(::attrs::DEFAULT_FONT, font);
+}
+
+/// Set the default background color of dialogs and the widgets in them.
+///
+/// Only dialogs created afterwards use the new color, so this is best called at the start of the
+/// closure passed to `show_gui()`. A color set on a widget itself takes precedence; see
+/// `Widget::set_bg_color()`.
+///
+/// ##Panics
+/// If called outside a valid KISS-UI context.
+pub fn set_global_bg_color(r: u8, g: u8, b: u8) {
+    set_global_color(::attrs::DLG_BG_COLOR, (r, g, b));
+}
+
+pub(crate) fn set_global_color(name: &'static str, (r, g, b): (u8, u8, u8)) {
+    set_global(name, &format!("{} {} {}", r, g, b));
+}
+
+fn get_global(name: &'static str) -> Option<String> {
+    unsafe {
+        let ptr = ::iup_sys::IupGetGlobal(name.as_cstr());
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+        }
+    }
+}
+
+fn set_global(name: &'static str, value: &str) {
+    assert_kiss_running!();
+
+    let value = CString::new(value).unwrap();
+    unsafe { ::iup_sys::IupSetStrGlobal(name.as_cstr(), value.as_ptr()); }
+}
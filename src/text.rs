@@ -1,46 +1,17 @@
 //! Widgets that can render and process text (labels, text boxes).
+//!
+//! `Label` lives in the `label` module and is re-exported here.
 
 use widget_prelude::*;
 
-use std::ffi::CString;
-use std::ptr;
-
-/// A static widget that renders text within its parent.
-pub struct Label(IUPPtr);
-
-impl Label {
-    /// Create a label with some text. 
-    pub fn new<S: Into<String>>(text: S) -> Label {
-        let c_text = CString::new(text.into()).unwrap();
-         unsafe {
-            let ptr = ::iup_sys::IupLabel(c_text.as_ptr());
-            Self::from_ptr(ptr)
-        }
-    }
+use ::callback::Callback;
+use utils::cstr::AsCStr;
 
-    /// Create a blank label. The text can be set later.
-    pub fn new_empty() -> Label {
-        unsafe { 
-            let ptr = ::iup_sys::IupLabel(ptr::null());
-            Self::from_ptr(ptr)       
-        }
-    }
-
-    /// Update the text of this label.
-    pub fn set_text(self, text: &str) -> Self {
-        self.set_str_attribute(::attrs::TITLE, text);
-        self
-    }
-
-    /// Get the text of this label.
-    pub fn get_text(&self) -> &str {
-        self.get_str_attribute(::attrs::TITLE).unwrap_or("")
-    }
-}
+use iup_sys::CallbackReturn;
 
-impl_widget! { Label, "label" }
+use std::ptr;
 
-impl ::image::ImageContainer for Label {}
+pub use label::Label;
 
 /// A widget that renders user-editable text.
 pub struct TextBox(IUPPtr);
@@ -83,18 +54,275 @@ impl TextBox {
     }
 
     /// Set the text of this textbox.
+    ///
+    /// This does not invoke any value changed callback; see `set_text_notify()`.
     pub fn set_text(self, value: &str) -> Self {
         self.set_str_attribute(::attrs::VALUE, value);
         self
     }
 
+    /// Set the text of this textbox, then invoke the callback set with `set_on_text_changed()`
+    /// or `OnValueChange::set_on_value_changed()`, if any, as if the user had typed the text.
+    pub fn set_text_notify(self, value: &str) -> Self {
+        self.set_text(value);
+
+        let callback = unsafe {
+            ::iup_sys::IupGetCallback(self.ptr(), ::attrs::VALUE_CHANGED_CB.as_cstr())
+        };
+
+        if let Some(callback) = callback {
+            if let CallbackReturn::Close = callback(self.ptr()) {
+                unsafe { ::iup_sys::IupExitLoop(); }
+            }
+        }
+
+        self
+    }
+
     /// Get the text value of this textbox.
     pub fn get_text(&self) -> &str {
         self.get_str_attribute(::attrs::VALUE).unwrap_or("")
     }    
+
+    /// Add a line of text to the end of this textbox.
+    ///
+    /// If the textbox is multiline and not empty, a newline is inserted before `line`.
+    /// Otherwise, `line` is appended to the current text as-is.
+    pub fn append_line(self, line: &str) -> Self {
+        self.set_str_attribute(::attrs::APPEND, line);
+        self
+    }
+
+    /// Get the number of lines in this textbox. Always at least 1.
+    pub fn get_line_count(&self) -> usize {
+        self.get_int_attribute(::attrs::LINE_COUNT) as usize
+    }
+
+    /// Get the line at the zero-based `idx`, without its newline, if it exists.
+    pub fn get_line(&self, idx: usize) -> Option<String> {
+        self.get_text().split('\n').nth(idx).map(Into::into)
+    }
+
+    /// Set if lines longer than the width of a multiline textbox should wrap onto the
+    /// following visible line, instead of being scrolled horizontally.
+    ///
+    /// This must be set before the textbox is shown; afterwards, it has no effect.
+    pub fn set_word_wrap(self, word_wrap: bool) -> Self {
+        self.set_bool_attribute(::attrs::WORD_WRAP, word_wrap);
+        self
+    }
+
+    /// Set if the user is prevented from editing the text of this textbox.
+    ///
+    /// The text can still be selected and copied, and changed programmatically.
+    pub fn set_readonly(self, readonly: bool) -> Self {
+        self.set_bool_attribute(::attrs::READ_ONLY, readonly);
+        self
+    }
+
+    /// Set if this single-line textbox hides its text, showing a bullet for each character, as
+    /// for passwords. `get_text()` still returns the real text.
+    ///
+    /// Only supported on Windows and GTK, and IUP may ignore changes after the textbox is shown;
+    /// toggling this at any time never changes the text, though. While the text is hidden, the
+    /// native control prevents it from being copied or cut to the clipboard.
+    pub fn set_password(self, password: bool) -> Self {
+        let text = self.get_text().to_owned();
+
+        self.set_bool_attribute(::attrs::PASSWORD, password);
+
+        // Guard against backends that lose the contents when the native control is updated.
+        if self.get_text() != text {
+            self.set_text(&text);
+        }
+
+        self
+    }
+
+    /// Check if this textbox hides its text. See `set_password()`.
+    pub fn is_password(&self) -> bool {
+        self.get_bool_attribute(::attrs::PASSWORD)
+    }
+
+    /// Restrict the text the user can enter to the given pattern, such as `/d+` for digits only.
+    ///
+    /// Input that does not match the pattern is rejected as it is typed, so the text of this
+    /// textbox always conforms to it, and value changed callbacks are only invoked for accepted
+    /// input. See the [IUP mask documentation][iup-mask] for the pattern syntax.
+    ///
+    /// [iup-mask]: http://webserver2.tecgraf.puc-rio.br/iup/en/attrib/iup_mask.html
+    pub fn set_mask(self, mask: &str) -> Self {
+        self.set_str_attribute(::attrs::MASK, mask);
+        self.set_opt_str_attribute(::attrs::KISS_MASK_INT, None::<String>);
+        self.set_opt_str_attribute(::attrs::KISS_MASK_FLOAT, None::<String>);
+        self
+    }
+
+    /// Restrict the text the user can enter to an integer between `min` and `max`, inclusive.
+    ///
+    /// See `set_mask()` for how input is rejected.
+    pub fn set_mask_int(self, min: i32, max: i32) -> Self {
+        let range = format!("{}:{}", min, max);
+        self.set_str_attribute(::attrs::MASK_INT, &*range);
+        self.set_str_attribute(::attrs::KISS_MASK_INT, range);
+        self.set_opt_str_attribute(::attrs::KISS_MASK_FLOAT, None::<String>);
+        self
+    }
+
+    /// Restrict the text the user can enter to a decimal number between `min` and `max`,
+    /// inclusive.
+    ///
+    /// See `set_mask()` for how input is rejected.
+    pub fn set_mask_float(self, min: f64, max: f64) -> Self {
+        let range = format!("{}:{}", min, max);
+        self.set_str_attribute(::attrs::MASK_FLOAT, &*range);
+        self.set_str_attribute(::attrs::KISS_MASK_FLOAT, range);
+        self.set_opt_str_attribute(::attrs::KISS_MASK_INT, None::<String>);
+        self
+    }
+
+    /// Check if the text of this textbox is a complete value for its mask.
+    ///
+    /// While typing, masks accept incomplete input, such as an empty string or a lone `-` for
+    /// `set_mask_int()`. For integer and decimal masks, this returns `true` only if the text is
+    /// a number within the range. For pattern masks, IUP already rejects any text that does not
+    /// match, so this returns `true`. Without a mask, this always returns `true`.
+    pub fn is_valid(&self) -> bool {
+        let text = self.get_text().trim();
+
+        if let Some((min, max)) = self.get_mask_range(::attrs::KISS_MASK_INT) {
+            text.parse::<i32>().is_ok_and(|val| min <= val && val <= max)
+        } else if let Some((min, max)) = self.get_mask_range(::attrs::KISS_MASK_FLOAT) {
+            text.parse::<f64>().is_ok_and(|val| min <= val && val <= max)
+        } else {
+            true
+        }
+    }
+
+    fn get_mask_range<T: ::std::str::FromStr>(&self, name: &'static str) -> Option<(T, T)> {
+        let range = self.get_str_attribute(name)?;
+        let mut bounds = range.splitn(2, ':');
+
+        match (bounds.next()?.parse(), bounds.next()?.parse()) {
+            (Ok(min), Ok(max)) => Some((min, max)),
+            _ => None,
+        }
+    }
+
+    /// Set a callback to be invoked after each edit of the text, by the user or by
+    /// `set_text_notify()`, with the full new text.
+    ///
+    /// ##Note
+    /// This uses the same IUP callback as `OnValueChange::set_on_value_changed()`, so the two
+    /// cannot be combined on the same textbox; whichever is set last takes effect.
+    pub fn set_on_text_changed<Cb>(self, on_text_changed: Cb) -> Self
+    where Cb: Callback<(Self, String)> {
+        callback_impl! {
+            ::attrs::VALUE_CHANGED_CB, self, on_text_changed, TextBox,
+            () -> (TextBox, String),
+            |textbox| Some((textbox, textbox.get_text().to_owned()))
+        }
+
+        self
+    }
+
+    /// Scroll a multiline textbox so the line at the zero-based `idx` is visible.
+    ///
+    /// To keep a log scrolled to the bottom, pass `get_line_count() - 1` after appending.
+    pub fn scroll_to_line(self, idx: usize) -> Self {
+        self.set_str_attribute(::attrs::SCROLL_TO, format!("{},1", idx + 1));
+        self
+    }
+
+    /// Move the caret before the character at the zero-based `pos`, counting characters rather
+    /// than bytes, so it always lands on a character boundary of the UTF-8 text. Newlines count
+    /// as one character.
+    ///
+    /// A `pos` past the end of the text moves the caret to the end.
+    pub fn set_caret(self, pos: usize) -> Self {
+        self.set_int_attribute(::attrs::CARET_POS, pos as i32);
+        self
+    }
+
+    /// Get the position of the caret, in characters from the start of the text.
+    /// See `set_caret()`.
+    pub fn get_caret(&self) -> usize {
+        self.get_int_attribute(::attrs::CARET_POS) as usize
+    }
+
+    /// Move the caret of a multiline textbox before the character at the zero-based `col` of the
+    /// zero-based `line`, counting characters rather than bytes.
+    ///
+    /// Positions past the end of the line or the text are moved to the end.
+    pub fn set_caret_line_col(self, line: usize, col: usize) -> Self {
+        self.set_str_attribute(::attrs::CARET, format!("{},{}", line + 1, col + 1));
+        self
+    }
+
+    /// Get the zero-based line and column of the caret in a multiline textbox.
+    /// See `set_caret_line_col()`.
+    pub fn get_caret_line_col(&self) -> (usize, usize) {
+        let caret = self.get_str_attribute(::attrs::CARET).unwrap_or("");
+        let mut coords = caret.splitn(2, ',').map(|n| n.trim().parse::<usize>().unwrap_or(1));
+
+        match (coords.next(), coords.next()) {
+            (Some(line), Some(col)) => (line.saturating_sub(1), col.saturating_sub(1)),
+            // Single-line textboxes report only the column.
+            (Some(col), None) => (0, col.saturating_sub(1)),
+            _ => (0, 0),
+        }
+    }
+
+    /// Select the characters from the zero-based `start` up to, but not including, `end`,
+    /// counting characters rather than bytes. See `set_caret()`.
+    ///
+    /// If `start == end`, the selection is cleared instead.
+    pub fn set_selection(self, start: usize, end: usize) -> Self {
+        if start == end {
+            self.set_const_str_attribute(::attrs::SELECTION_POS, ::attrs::values::NONE);
+        } else {
+            let (start, end) = (start.min(end), start.max(end));
+            self.set_str_attribute(::attrs::SELECTION_POS, format!("{}:{}", start, end));
+        }
+
+        self
+    }
+
+    /// Get the range of selected characters as `(start, end)`, where `end` is exclusive.
+    /// See `set_selection()`.
+    ///
+    /// Returns `None` if no text is selected.
+    pub fn get_selection(&self) -> Option<(usize, usize)> {
+        let selection = self.get_str_attribute(::attrs::SELECTION_POS)?;
+        let mut bounds = selection.splitn(2, ':');
+
+        match (bounds.next()?.parse(), bounds.next()?.parse()) {
+            (Ok(start), Ok(end)) if start < end => Some((start, end)),
+            _ => None,
+        }
+    }
+
+    /// Get the selected text.
+    ///
+    /// Returns `None` if no text is selected.
+    pub fn get_selected_text(&self) -> Option<String> {
+        self.get_str_attribute(::attrs::SELECTED_TEXT)
+            .and_then(|text| if text.is_empty() { None } else { Some(text.to_owned()) })
+    }
 }
 
-impl_widget! { TextBox, "text" }
+// Spin boxes are `IupText`s too, but are cast to `Spin` instead.
+impl_widget! { TextBox, "text", |widget| !widget.get_bool_attribute(::attrs::SPIN) }
+
+impl_on_map! { TextBox }
 
 impl_on_value_change! { TextBox }
 
+impl_on_key_press! { TextBox }
+
+impl_on_focus! { TextBox }
+
+impl_on_mouse_button! { TextBox }
+
+impl_on_mouse_motion! { TextBox }
+
@@ -2,6 +2,16 @@
 use widget_prelude::*;
 use ::callback::Callback;
 
+use std::cell::RefCell;
+use std::cmp;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+thread_local! {
+    // When the current interval of each running timer began.
+    static INTERVAL_STARTS: RefCell<HashMap<IUPPtr, Instant>> = RefCell::new(HashMap::new());
+}
+
 /// A timer that can invoke a callback on a configurable interval.
 /// 
 /// ##Note: Not a Renderable Widget
@@ -25,32 +35,214 @@ impl Timer {
     }
 
     /// Set the timer interval in milliseconds.
+    ///
+    /// If the timer is running, it is restarted so the new interval is used from the next tick
+    /// onward. If it is paused, it resumes with a full new interval.
     pub fn set_interval(self, time: u32) -> Self {
+        self.set_opt_str_attribute(::attrs::KISS_INTERVAL, None::<String>);
+        self.set_opt_str_attribute(::attrs::KISS_REMAINING, None::<String>);
         self.set_int_attribute(::attrs::TIME, time as i32);
+
+        if self.is_running() {
+            self.restart();
+        }
+
         self
     }
 
     /// Set a callback to be invoked when the timer interval elapses.
     /// The callback will be invoked on every interval until `.stop()` is called.
-    pub fn set_on_interval<Cb>(self, on_interval: Cb) -> Self where Cb: Callback<Self> {
-       callback_impl! { ::attrs::ACTION_CB, self, on_interval, Timer }
+    pub fn set_on_interval<Cb>(self, mut on_interval: Cb) -> Self where Cb: Callback<Self> {
+       callback_impl! {
+           ::attrs::ACTION_CB, self,
+           move |timer: Timer| {
+               timer.next_interval();
+               on_interval.on_callback(timer)
+           },
+           Timer
+       }
        self
     }
 
     /// Start the timer. The callback will be invoked when the next interval elapses.
     pub fn start(self) -> Self {
-        self.set_bool_attribute(::attrs::RUN, true);
+        self.set_bool_attribute(::attrs::KISS_PAUSED, false);
+        self.end_remainder();
+        self.restart();
         self
     }
 
     /// Stop the timer. The callback will not be invoked until the timer is restarted.
+    ///
+    /// If the timer was paused, it will no longer resume with `.resume()`.
     pub fn stop(self) -> Self {
         self.set_bool_attribute(::attrs::RUN, false);
+        self.set_bool_attribute(::attrs::KISS_PAUSED, false);
+        self.end_remainder();
         self
     } 
+
+    /// Pause the timer if it is running. It can be restarted with `.resume()`.
+    ///
+    /// Does nothing if the timer is not running.
+    pub fn pause(self) -> Self {
+        if self.is_running() {
+            self.set_bool_attribute(::attrs::RUN, false);
+            self.set_bool_attribute(::attrs::KISS_PAUSED, true);
+
+            let elapsed = INTERVAL_STARTS.with(|starts| starts.borrow().get(&self.ptr()).cloned())
+                .map_or(0, |start| start.elapsed().as_millis() as u64);
+            let remaining = (self.interval() as u64).saturating_sub(elapsed);
+            self.set_int_attribute(::attrs::KISS_REMAINING, remaining as i32);
+        }
+
+        self
+    }
+
+    /// Restart the timer if it was paused with `.pause()`. The callback will be invoked when the
+    /// rest of the interval that was interrupted by the pause elapses, then on every full
+    /// interval again.
+    ///
+    /// Does nothing if the timer is not paused.
+    pub fn resume(self) -> Self {
+        if !self.is_paused() {
+            return self;
+        }
+
+        self.set_bool_attribute(::attrs::KISS_PAUSED, false);
+
+        let interval = self.interval();
+        let remaining = self.get_str_attribute(::attrs::KISS_REMAINING)
+            .and_then(|remaining| remaining.parse().ok())
+            .map_or(interval, |remaining: u32| cmp::min(remaining, interval));
+        self.set_opt_str_attribute(::attrs::KISS_REMAINING, None::<String>);
+
+        if remaining < interval {
+            // Runs once for the rest of the interval; `next_interval()` restores the full one.
+            self.set_int_attribute(::attrs::KISS_INTERVAL, interval as i32);
+            // IUP does not accept an interval of zero.
+            self.set_int_attribute(::attrs::TIME, cmp::max(remaining, 1) as i32);
+        }
+
+        self.restart();
+
+        // Backdated, so pausing again before the next tick leaves the right remainder.
+        let elapsed = Duration::from_millis((interval - remaining) as u64);
+        let start = Instant::now().checked_sub(elapsed).unwrap_or_else(Instant::now);
+        INTERVAL_STARTS.with(|starts| starts.borrow_mut().insert(self.ptr(), start));
+
+        self
+    }
+
+    /// Get the full interval of this timer, even while it runs for the rest of one after
+    /// `resume()`.
+    fn interval(self) -> u32 {
+        self.get_str_attribute(::attrs::KISS_INTERVAL)
+            .and_then(|interval| interval.parse().ok())
+            .unwrap_or_else(|| self.get_int_attribute(::attrs::TIME) as u32)
+    }
+
+    /// Restore the full interval if the timer is running for the rest of one after `resume()`.
+    ///
+    /// Returns `true` if it was.
+    fn end_remainder(self) -> bool {
+        let interval = self.get_str_attribute(::attrs::KISS_INTERVAL)
+            .and_then(|interval| interval.parse::<i32>().ok());
+        self.set_opt_str_attribute(::attrs::KISS_REMAINING, None::<String>);
+
+        match interval {
+            Some(interval) => {
+                self.set_opt_str_attribute(::attrs::KISS_INTERVAL, None::<String>);
+                self.set_int_attribute(::attrs::TIME, interval);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Invoked on every tick, before the callback.
+    fn next_interval(self) {
+        // IUP only applies a new `TIME` when the timer is restarted.
+        if self.end_remainder() {
+            self.restart();
+        } else {
+            self.mark_interval_start();
+        }
+    }
+
+    /// Start the underlying IUP timer over, beginning a new interval.
+    fn restart(self) {
+        self.set_bool_attribute(::attrs::RUN, false);
+        self.set_bool_attribute(::attrs::RUN, true);
+        self.mark_interval_start();
+    }
+
+    fn mark_interval_start(self) {
+        let prev = INTERVAL_STARTS.with(|starts| {
+            starts.borrow_mut().insert(self.ptr(), Instant::now())
+        });
+
+        if prev.is_none() {
+            ::callback::on_destroy(self, |timer| INTERVAL_STARTS.with(|starts| {
+                starts.borrow_mut().remove(&timer);
+            }));
+        }
+    }
+
+    /// Check if the timer is running, i.e. started and not stopped or paused.
+    pub fn is_running(&self) -> bool {
+        self.get_str_attribute(::attrs::RUN) == Some("YES")
+    }
+
+    /// Check if the timer was paused with `.pause()` and not resumed or stopped since.
+    pub fn is_paused(&self) -> bool {
+        self.get_str_attribute(::attrs::KISS_PAUSED) == Some("YES")
+    }
 }
 
 impl_widget! { Timer, "timer" }
 
 impl Destroy for Timer {}
 
+
+/// The value returned from a frame function passed to `animate()`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum AnimAction {
+    /// Keep the animation running, to invoke the frame function again on the next frame.
+    Continue,
+    /// End the animation. The frame function will not be invoked again.
+    Stop,
+}
+
+/// Invoke `on_frame` about `fps` times per second until it returns `AnimAction::Stop`.
+///
+/// `on_frame` receives the time elapsed since the previous frame, or since `animate()` was
+/// called for the first frame. This is measured, not derived from `fps`, so animations stay in
+/// step with the clock when frames are delayed, e.g. while the event loop is busy.
+///
+/// The animation is driven by a `Timer` owned by this function, which is destroyed when the
+/// animation stops.
+///
+/// ##Panics
+/// * If called outside a valid KISS-UI context.
+/// * If `fps` is zero.
+pub fn animate<F>(fps: u32, mut on_frame: F) where F: FnMut(Duration) -> AnimAction + 'static {
+    assert_kiss_running!();
+    assert!(fps > 0, "`fps` must be greater than zero");
+
+    let mut last_frame = Instant::now();
+
+    Timer::new()
+        .set_interval(cmp::max(1000 / fps, 1))
+        .set_on_interval(move |timer: Timer| {
+            let now = Instant::now();
+            let dt = now - last_frame;
+            last_frame = now;
+
+            if on_frame(dt) == AnimAction::Stop {
+                // The callback is not freed until it returns, so this is safe to do here.
+                timer.stop().destroy();
+            }
+        })
+        .start();
+}
@@ -0,0 +1,128 @@
+//! Checkboxes and radio buttons that can be toggled by the user.
+
+use widget_prelude::*;
+
+use ::callback::Callback;
+
+use std::ffi::CString;
+use std::ptr;
+
+/// The state of a `Toggle`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ToggleState {
+    /// The toggle is unchecked.
+    Off,
+    /// The toggle is checked.
+    On,
+    /// The toggle is neither checked nor unchecked. Only possible in three-state mode.
+    NotDef,
+}
+
+impl ToggleState {
+    fn as_cstr(self) -> &'static str {
+        use self::ToggleState::*;
+
+        match self {
+            Off => ::attrs::values::OFF,
+            On => ::attrs::values::ON,
+            NotDef => ::attrs::values::NOTDEF,
+        }
+    }
+}
+
+/// A widget that can be checked or unchecked by the user, rendered as a checkbox.
+///
+/// When placed inside a `container::RadioGroup`, it will instead render as a radio button, and
+/// only one toggle in the group can be checked at a time.
+///
+/// For more info, see the [`IupToggle`][iup-toggle] documentation.
+///
+/// [iup-toggle]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iuptoggle.html
+pub struct Toggle(IUPPtr);
+
+impl Toggle {
+    /// Create a new, unchecked toggle with the given label.
+    pub fn new(label: &str) -> Toggle {
+        let c_label = CString::new(label).unwrap();
+
+        unsafe {
+            let ptr = ::iup_sys::IupToggle(c_label.as_ptr(), ptr::null());
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Set the label of this toggle. Can be blank.
+    ///
+    /// Equivalent to `Titled::set_title()`.
+    pub fn set_label<L: Into<String>>(self, label: L) -> Self {
+        self.set_title(label)
+    }
+
+    /// Check or uncheck this toggle.
+    pub fn set_checked(self, checked: bool) -> Self {
+        self.set_state(if checked { ToggleState::On } else { ToggleState::Off })
+    }
+
+    /// Returns `true` if this toggle is checked.
+    pub fn is_checked(&self) -> bool {
+        self.get_state() == ToggleState::On
+    }
+
+    /// Set if this toggle can be in the `NotDef` state, in addition to `On` and `Off`.
+    ///
+    /// Has no effect on toggles inside a `RadioGroup`.
+    pub fn set_three_state(self, three_state: bool) -> Self {
+        self.set_bool_attribute(::attrs::THREE_STATE, three_state);
+        self
+    }
+
+    /// Set the state of this toggle.
+    ///
+    /// `ToggleState::NotDef` is only valid in three-state mode.
+    pub fn set_state(self, state: ToggleState) -> Self {
+        self.set_const_str_attribute(::attrs::VALUE, state.as_cstr());
+        self
+    }
+
+    /// Get the current state of this toggle.
+    pub fn get_state(&self) -> ToggleState {
+        match self.get_str_attribute(::attrs::VALUE) {
+            Some("ON") => ToggleState::On,
+            Some("NOTDEF") => ToggleState::NotDef,
+            _ => ToggleState::Off,
+        }
+    }
+
+    /// Set a callback to be invoked when the user checks or unchecks this toggle, with the new
+    /// checked state.
+    ///
+    /// Inside a `RadioGroup`, when the selection moves from one toggle to another, this is invoked
+    /// on the previously checked toggle with `false` and on the newly checked one with `true`.
+    ///
+    /// In three-state mode, the `NotDef` state is reported as `false`; use `.get_state()` to
+    /// distinguish it from `Off`.
+    pub fn set_on_toggled<Cb>(self, on_toggled: Cb) -> Self where Cb: Callback<(Self, bool)> {
+        callback_impl! {
+            ::attrs::ACTION, self, on_toggled, Toggle,
+            (state: ::libc::c_int) -> (Toggle, bool),
+            |toggle| Some((toggle, state == 1));
+            invoke_action_callback
+        }
+
+        self
+    }
+}
+
+impl_widget! { Toggle, "toggle" }
+
+impl_on_map! { Toggle }
+
+impl_on_action! { Toggle, ::attrs::ACTION, (_state: ::libc::c_int) }
+
+impl Titled for Toggle {}
+
+impl_on_key_press! { Toggle }
+
+impl_on_focus! { Toggle }
+
+impl ::image::ImageContainer for Toggle {}
@@ -0,0 +1,206 @@
+//! Icons in the system tray (notification area) of the desktop.
+//!
+//! The system tray is only supported on Windows and GTK.
+
+use callback::{Callback, CallbackMap};
+use image::Image;
+use menu::Menu;
+use widget_prelude::*;
+
+use iup_sys::CallbackReturn;
+use libc::c_int;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::{mem, ptr};
+
+thread_local! {
+    static TRAY_MENUS: RefCell<HashMap<IUPPtr, Menu>> = RefCell::new(HashMap::new());
+    static CLICK_CALLBACKS: CallbackMap<TrayIcon> = RefCell::new(HashMap::new());
+    static DOUBLE_CLICK_CALLBACKS: CallbackMap<TrayIcon> = RefCell::new(HashMap::new());
+}
+
+/// An icon in the system tray, which can show a tooltip and a menu and notify client code when
+/// it is clicked.
+///
+/// The icon is shown as soon as it is created, and removed when it is destroyed or when
+/// `kiss_ui::show_gui()` returns. It is backed by a hidden dialog, which is never shown.
+///
+/// For more info, see the `TRAY` attribute in the [`IupDialog`][iup-dialog] documentation.
+///
+/// [iup-dialog]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iupdialog.html
+pub struct TrayIcon(IUPPtr);
+
+impl TrayIcon {
+    /// Add an icon showing `image` to the system tray.
+    ///
+    /// ##Panics
+    /// If called outside a valid KISS-UI context.
+    pub fn new(image: Image) -> TrayIcon {
+        assert_kiss_running!();
+
+        let tray = unsafe {
+            let ptr = ::iup_sys::IupDialog(ptr::null_mut());
+            // The tray icon is only created for a dialog that has a native window.
+            ::iup_sys::IupMap(ptr);
+            Self::from_ptr(ptr)
+        };
+
+        let callback = unsafe {
+            mem::transmute::<
+                extern "C" fn(IUPPtr, c_int, c_int, c_int) -> CallbackReturn,
+                ::iup_sys::Icallback
+            >(tray_click_callback)
+        };
+
+        tray.set_callback(::attrs::TRAY_CLICK_CB, callback);
+        tray.set_image(image);
+        tray.set_const_str_attribute(::attrs::TRAY, ::attrs::values::YES);
+        tray
+    }
+
+    /// Replace the image of this icon.
+    pub fn set_image(self, image: Image) -> Self {
+        self.set_attr_handle(::attrs::TRAY_IMAGE, image);
+        self
+    }
+
+    /// Set the text shown when the mouse hovers over this icon.
+    pub fn set_tooltip(self, text: &str) -> Self {
+        self.set_str_attribute(::attrs::TRAY_TIP, text);
+        self
+    }
+
+    /// Show a notification balloon pointing at this icon, with a `title` in bold above the
+    /// `message`. The platform hides it after a few seconds or when it is clicked.
+    ///
+    /// The title and message are truncated to the lengths the platform supports. Does nothing
+    /// if this icon is not in the tray.
+    ///
+    /// ##Note
+    /// Notification balloons are only supported on Windows; elsewhere, this does nothing.
+    pub fn show_notification(self, title: &str, message: &str, kind: NotifyKind) -> Self {
+        // The limits of `NOTIFYICONDATA`, minus the nul terminators.
+        const MAX_TITLE_LEN: usize = 63;
+        const MAX_MESSAGE_LEN: usize = 255;
+
+        if self.get_str_attribute(::attrs::TRAY) != Some("YES") {
+            return self;
+        }
+
+        // The balloon is shown by setting the tooltip, so the actual tooltip is put back after.
+        let tooltip = self.get_str_attribute(::attrs::TRAY_TIP).map(String::from);
+
+        self.set_const_str_attribute(::attrs::TRAY_TIP_BALLOON, ::attrs::values::YES);
+        self.set_str_attribute(::attrs::TRAY_TIP_BALLOON_TITLE, truncate(title, MAX_TITLE_LEN));
+        self.set_const_str_attribute(::attrs::TRAY_TIP_BALLOON_TITLE_ICON, kind.as_cstr());
+        self.set_str_attribute(::attrs::TRAY_TIP, truncate(message, MAX_MESSAGE_LEN));
+
+        self.set_const_str_attribute(::attrs::TRAY_TIP_BALLOON, ::attrs::values::NO);
+        self.set_opt_str_attribute(::attrs::TRAY_TIP, tooltip);
+        self
+    }
+
+    /// Set a menu to popup when this icon is right-clicked.
+    ///
+    /// The previous menu of this icon, if any, is destroyed, and so is this menu when the icon
+    /// is destroyed; in both cases only once no other widget uses the menu as its context or
+    /// tray menu.
+    pub fn set_menu(self, menu: Menu) -> Self {
+        let prev = TRAY_MENUS.with(|menus| menus.borrow_mut().insert(self.ptr(), menu));
+        // Before releasing `prev`, which may be the same menu.
+        ::menu::retain_menu(menu);
+
+        match prev {
+            Some(prev) => ::menu::release_menu(prev),
+            None => ::callback::on_destroy(self, |tray| {
+                if let Some(menu) = TRAY_MENUS.with(|menus| menus.borrow_mut().remove(&tray)) {
+                    ::menu::release_menu(menu);
+                }
+            }),
+        }
+
+        self
+    }
+
+    /// Set a callback to be invoked when this icon is clicked with the left mouse button.
+    pub fn set_on_click<Cb>(self, on_click: Cb) -> Self where Cb: Callback<Self> {
+        ::callback::store_callback(&CLICK_CALLBACKS, self, Box::new(on_click));
+        self
+    }
+
+    /// Set a callback to be invoked when this icon is double-clicked with the left mouse button.
+    ///
+    /// The first click is also reported to the `set_on_click()` callback.
+    pub fn set_on_double_click<Cb>(self, on_double_click: Cb) -> Self where Cb: Callback<Self> {
+        ::callback::store_callback(&DOUBLE_CLICK_CALLBACKS, self, Box::new(on_double_click));
+        self
+    }
+}
+
+// An `IupDialog` with `TRAY` set; see `Dialog`.
+impl_widget! { TrayIcon, "dialog", |widget| widget.get_bool_attribute(::attrs::TRAY) }
+
+/// The icon shown next to the title of a notification from `TrayIcon::show_notification()`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum NotifyKind {
+    Info,
+    Warning,
+    Error,
+}
+
+impl NotifyKind {
+    fn as_cstr(self) -> &'static str {
+        use self::NotifyKind::*;
+
+        match self {
+            Info => cstr!("1"),
+            Warning => cstr!("2"),
+            Error => cstr!("3"),
+        }
+    }
+}
+
+/// Cut `text` down to at most `max_len` bytes, without splitting a character.
+fn truncate(text: &str, max_len: usize) -> &str {
+    if text.len() <= max_len {
+        return text;
+    }
+
+    let end = (0 ..= max_len).rev().find(|&idx| text.is_char_boundary(idx)).unwrap_or(0);
+    &text[.. end]
+}
+
+impl Destroy for TrayIcon {}
+
+extern "C" fn tray_click_callback(
+    element: IUPPtr, button: c_int, pressed: c_int, double_click: c_int
+) -> CallbackReturn {
+    // IUP reports the buttons as 1 to 3 here. GTK only reports presses, never releases.
+    if pressed == 0 {
+        return CallbackReturn::Default;
+    }
+
+    match button {
+        1 => {
+            let callbacks = if double_click != 0 {
+                &DOUBLE_CLICK_CALLBACKS
+            } else {
+                &CLICK_CALLBACKS
+            };
+            let tray = unsafe { TrayIcon::from_ptr(element) };
+            ::callback::invoke_callback(callbacks, element, tray).to_cb_return()
+        },
+        3 => {
+            let menu = TRAY_MENUS.with(|menus| menus.borrow().get(&element).cloned());
+
+            if let Some(menu) = menu {
+                use iup_sys::IUP_MOUSEPOS;
+                unsafe { ::iup_sys::IupPopup(menu.ptr(), IUP_MOUSEPOS, IUP_MOUSEPOS); }
+            }
+
+            CallbackReturn::Default
+        },
+        _ => CallbackReturn::Default,
+    }
+}
@@ -0,0 +1,204 @@
+//! Hierarchical trees of branches and leaves.
+
+use widget_prelude::*;
+
+use callback::Callback;
+
+use libc::c_void;
+
+use std::cell::Cell;
+
+thread_local! {
+    // The next `NodeId` to hand out; 0 is reserved for the root.
+    static NEXT_NODE_ID: Cell<usize> = const { Cell::new(1) };
+}
+
+/// Identifies a node of a `Tree`.
+///
+/// A `NodeId` stays valid while other nodes are added or removed. It is invalidated when its
+/// node, or a branch containing it, is removed; methods given an invalid `NodeId` do nothing
+/// or return `None`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct NodeId(usize);
+
+const ROOT: NodeId = NodeId(0);
+
+/// A widget that shows a hierarchy of nodes, where branches can be expanded to show the nodes
+/// they contain and collapsed to hide them.
+///
+/// A new tree contains a single branch, the root, to which all other nodes are added.
+///
+/// For more info, see the [`IupTree`][iup-tree] documentation.
+///
+/// [iup-tree]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iuptree.html
+pub struct Tree(IUPPtr);
+
+impl Tree {
+    /// Create a new tree containing only the root branch, which has no text.
+    pub fn new() -> Tree {
+        unsafe {
+            let ptr = ::iup_sys::IupTree();
+            Self::from_ptr(ptr)
+        }
+    }
+
+    /// Get the root branch of this tree, which is never removed.
+    pub fn root(self) -> NodeId {
+        ROOT
+    }
+
+    /// Add a branch with `text` after the existing children of `parent`.
+    ///
+    /// Returns `None` if `parent` is not a branch of this tree.
+    pub fn add_branch(self, parent: NodeId, text: &str) -> Option<NodeId> {
+        self.add_node(parent, text, ::attrs::ADD_BRANCH, ::attrs::INSERT_BRANCH)
+    }
+
+    /// Add a leaf with `text` after the existing children of `parent`.
+    ///
+    /// Returns `None` if `parent` is not a branch of this tree.
+    pub fn add_leaf(self, parent: NodeId, text: &str) -> Option<NodeId> {
+        self.add_node(parent, text, ::attrs::ADD_LEAF, ::attrs::INSERT_LEAF)
+    }
+
+    fn add_node(self, parent: NodeId, text: &str, add: &'static str, insert: &'static str)
+    -> Option<NodeId> {
+        let parent_idx = self.index_of(parent)?;
+
+        if self.get_str_attribute_id(::attrs::KIND, parent_idx) != Some("BRANCH") {
+            return None;
+        }
+
+        // `ADD*` makes the node the first child of the branch, `INSERT*` puts it after a sibling.
+        if self.get_int_attribute_id(::attrs::CHILD_COUNT, parent_idx) == 0 {
+            self.set_str_attribute_id(add, parent_idx, text);
+        } else {
+            let last_child = self.get_int_attribute_id(::attrs::LAST, parent_idx + 1);
+            self.set_str_attribute_id(insert, last_child, text);
+        }
+
+        let idx = self.get_int_attribute(::attrs::LAST_ADD_NODE);
+        let node = NodeId(NEXT_NODE_ID.with(|next| next.replace(next.get() + 1)));
+
+        unsafe { ::iup_sys::IupTreeSetUserId(self.ptr(), idx, node.0 as *mut c_void); }
+
+        Some(node)
+    }
+
+    /// Remove `node` and everything it contains, invalidating their `NodeId`s.
+    ///
+    /// If `node` is the root, only its children are removed.
+    pub fn remove(self, node: NodeId) -> Self {
+        if let Some(idx) = self.index_of(node) {
+            let which = if node == ROOT { "CHILDREN" } else { "SELECTED" };
+            self.set_str_attribute_id(::attrs::DEL_NODE, idx, which);
+        }
+
+        self
+    }
+
+    /// Expand or collapse the branch `node`. Does nothing if `node` is a leaf.
+    pub fn set_expanded(self, node: NodeId, expanded: bool) -> Self {
+        if let Some(idx) = self.index_of(node) {
+            let state = if expanded { "EXPANDED" } else { "COLLAPSED" };
+            self.set_str_attribute_id(::attrs::STATE, idx, state);
+        }
+
+        self
+    }
+
+    /// Check if the branch `node` is expanded. Always `false` for leaves.
+    pub fn is_expanded(self, node: NodeId) -> bool {
+        self.index_of(node)
+            .is_some_and(|idx| self.get_str_attribute_id(::attrs::STATE, idx) == Some("EXPANDED"))
+    }
+
+    /// Set the text of `node`.
+    pub fn set_text(self, node: NodeId, text: &str) -> Self {
+        if let Some(idx) = self.index_of(node) {
+            self.set_str_attribute_id(::attrs::TITLE, idx, text);
+        }
+
+        self
+    }
+
+    /// Get the text of `node`.
+    pub fn get_text(self, node: NodeId) -> Option<String> {
+        let idx = self.index_of(node)?;
+        self.get_str_attribute_id(::attrs::TITLE, idx).map(String::from)
+    }
+
+    /// Get the selected node, if any.
+    pub fn get_selected(self) -> Option<NodeId> {
+        self.node_at(self.get_int_attribute(::attrs::VALUE))
+    }
+
+    /// Select `node`, deselecting the previously selected one.
+    pub fn set_selected(self, node: NodeId) -> Self {
+        if let Some(idx) = self.index_of(node) {
+            self.set_int_attribute(::attrs::VALUE, idx);
+        }
+
+        self
+    }
+
+    /// Set a callback to be invoked when the user selects a node.
+    pub fn set_on_selection_changed<Cb>(self, on_selection_changed: Cb) -> Self
+    where Cb: Callback<(Self, NodeId)> {
+        callback_impl! {
+            ::attrs::SELECTION_CB, self, on_selection_changed, Tree,
+            (id: ::libc::c_int, status: ::libc::c_int) -> (Tree, NodeId),
+            |tree| if status == 1 { tree.node_at(id).map(|node| (tree, node)) } else { None }
+        }
+
+        self
+    }
+
+    /// Set a callback to be invoked when the user expands a branch, before its children are
+    /// shown.
+    ///
+    /// Return `CallbackStatus::Ignore` to keep the branch collapsed.
+    pub fn set_on_node_expanded<Cb>(self, on_node_expanded: Cb) -> Self
+    where Cb: Callback<(Self, NodeId)> {
+        callback_impl! {
+            ::attrs::BRANCH_OPEN_CB, self, on_node_expanded, Tree,
+            (id: ::libc::c_int) -> (Tree, NodeId),
+            |tree| tree.node_at(id).map(|node| (tree, node))
+        }
+
+        self
+    }
+
+    // IUP identifies nodes by their position in the tree, so the `NodeId` is kept as the
+    // node's user ID and looked up when needed.
+    fn index_of(self, node: NodeId) -> Option<i32> {
+        if node == ROOT {
+            return Some(0);
+        }
+
+        match unsafe { ::iup_sys::IupTreeGetId(self.ptr(), node.0 as *mut c_void) } {
+            -1 => None,
+            idx => Some(idx),
+        }
+    }
+
+    fn node_at(self, idx: i32) -> Option<NodeId> {
+        if idx < 0 {
+            return None;
+        }
+
+        match unsafe { ::iup_sys::IupTreeGetUserId(self.ptr(), idx) } as usize {
+            0 if idx == 0 => Some(ROOT),
+            0 => None,
+            id => Some(NodeId(id)),
+        }
+    }
+}
+
+impl_widget! { Tree, "tree" }
+
+impl_on_map! { Tree }
+
+impl_on_key_press! { Tree }
+
+impl_on_focus! { Tree }
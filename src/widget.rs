@@ -3,12 +3,20 @@
 use utils::cstr::AsCStr;
 
 use base::{BaseWidget, Downcast};
+use callback::Callback;
 use dialog::Dialog;
+use image::Image;
+use menu::Menu;
+use mouse::Cursor;
 
 use iup_sys;
 
+use std::cell::Cell;
 use std::ffi::{CStr, CString};
-use std::ptr;
+use std::fmt;
+use std::ops::{BitOr, Deref};
+use std::rc::Rc;
+use std::{mem, ptr};
 
 /// Trait implemented for all widget types.
 ///
@@ -52,6 +60,120 @@ pub trait Widget: IUPWidget {
         self
     }
 
+    /// Check if this widget is actually on-screen.
+    ///
+    /// This is `false` if the widget has not been shown yet, or if it or any of its parents,
+    /// including its dialog, is hidden, even if the widget itself was never hidden, so it may
+    /// differ from the last value passed to `set_visible()`.
+    fn is_visible(&self) -> bool {
+        self.get_str_attribute(::attrs::WID).is_some()
+            && all_ancestors(self.ptr(), |widget| {
+                widget.get_str_attribute(::attrs::VISIBLE) != Some("NO")
+            })
+    }
+
+    /// Check if this widget reacts to user interaction.
+    ///
+    /// As in IUP, disabling a container disables all of its children, so this is `false` if
+    /// this widget or any of its parents is disabled, which may differ from the last value passed
+    /// to `set_enabled()`. Widgets that do not support being disabled are always enabled.
+    fn is_enabled(&self) -> bool {
+        all_ancestors(self.ptr(), |widget| widget.get_str_attribute(::attrs::ACTIVE) != Some("NO"))
+    }
+
+    /// Set in which directions this widget grows to fill the free space in its container.
+    ///
+    /// Containers default to `Expand::Yes`, and most other widgets to `Expand::No`.
+    /// A container only expands in a direction if at least one of its children does.
+    fn set_expand(self, expand: Expand) -> Self {
+        self.set_const_str_attribute(::attrs::EXPAND, expand.as_cstr());
+        self
+    }
+
+    /// Get in which directions this widget grows to fill the free space in its container.
+    fn get_expand(&self) -> Expand {
+        Expand::from_str(self.get_str_attribute(::attrs::EXPAND).unwrap_or("NO"))
+    }
+
+    /// Move this widget after all of its siblings and above them on screen, e.g. to bring one of
+    /// several overlapping widgets in an `Absolute` container to the top.
+    ///
+    /// For a dialog, this raises its window above the other windows.
+    fn bring_to_front(self) -> Self {
+        let parent = unsafe { iup_sys::IupGetParent(self.ptr()) };
+
+        if !parent.is_null() {
+            // Unlike detaching and inserting, this keeps the native widget and its state.
+            unsafe { iup_sys::IupReparent(self.ptr(), parent, ptr::null_mut()); }
+        }
+
+        restack(self, ::attrs::values::TOP, parent)
+    }
+
+    /// Move this widget before all of its siblings and below them on screen.
+    ///
+    /// For a dialog, this lowers its window below the other windows.
+    fn send_to_back(self) -> Self {
+        let parent = unsafe { iup_sys::IupGetParent(self.ptr()) };
+
+        if !parent.is_null() {
+            let first = unsafe { iup_sys::IupGetChild(parent, 0) };
+
+            if first != self.ptr() {
+                unsafe { iup_sys::IupReparent(self.ptr(), parent, first); }
+            }
+        }
+
+        restack(self, ::attrs::values::BOTTOM, parent)
+    }
+
+    /// Move the keyboard focus to this widget.
+    ///
+    /// Does nothing if the widget has not been shown yet, is hidden or disabled, or cannot
+    /// receive the focus.
+    fn set_focus(self) -> Self {
+        if self.is_visible() && self.is_enabled() {
+            unsafe { iup_sys::IupSetFocus(self.ptr()); }
+        }
+
+        self
+    }
+
+    /// Set if this widget can receive the keyboard focus, and so is visited when pressing Tab.
+    /// **Default: `true`** for widgets that take keyboard input.
+    ///
+    /// This must be set before the widget is shown to affect IUP's own Tab navigation; an order
+    /// set with `Dialog::set_focus_order()` skips the widget either way.
+    fn set_tab_stop(self, tab_stop: bool) -> Self {
+        self.set_bool_attribute(::attrs::CAN_FOCUS, tab_stop);
+        self
+    }
+
+    /// Check if this widget currently has the keyboard focus.
+    fn has_focus(self) -> bool {
+        unsafe { iup_sys::IupGetFocus() == self.ptr() }
+    }
+
+    /// Recompute the layout of this widget's dialog, e.g. after adding or removing children or
+    /// changing sizes while it is on-screen.
+    ///
+    /// Does nothing if this widget has not been shown yet or is not attached to a dialog.
+    fn refresh(&self) {
+        if is_mapped(self) {
+            unsafe { iup_sys::IupRefresh(self.ptr()); }
+        }
+    }
+
+    /// Recompute the layout of this widget's children only, leaving the rest of the dialog alone.
+    ///
+    /// This is cheaper than `refresh()`, but the widget itself won't grow or shrink to fit.
+    /// Does nothing if this widget has not been shown yet or is not attached to a dialog.
+    fn refresh_children(&self) {
+        if is_mapped(self) {
+            unsafe { iup_sys::IupRefreshChildren(self.ptr()); }
+        }
+    }
+
     /// Set the position of this widget relative to the top-left corner of its parent.
     ///
     /// Does nothing if the widget is not renderable or not attached to a parent.
@@ -116,15 +238,293 @@ pub trait Widget: IUPWidget {
     /// Get the rendered size of this widget, in pixels.
     ///
     /// Returns `(0, 0)` if this widget has no rendered size.
+    ///
+    /// The size is in logical pixels if `kiss_ui::set_dpi_aware(true)` was called.
     fn get_size_pixels(self) -> (u32, u32) {
         let (width, height) = self.get_int2_attribute(::attrs::RASTERSIZE);
-        (width as u32, height as u32)
+        (::dpi::to_logical(width as u32), ::dpi::to_logical(height as u32))
+    }
+
+    /// Set the minimum size of this widget, in pixels. `0` leaves that dimension unconstrained.
+    ///
+    /// The widget will not shrink below this size, even if it expands, and a dialog cannot be
+    /// resized below it by the user.
+    ///
+    /// The size is in logical pixels if `kiss_ui::set_dpi_aware(true)` was called.
+    fn set_min_size(self, width: u32, height: u32) -> Self {
+        let (width, height) = (::dpi::to_physical(width), ::dpi::to_physical(height));
+        self.set_str_attribute(::attrs::MIN_SIZE, format!("{}x{}", width, height));
+        self
+    }
+
+    /// Set the maximum size of this widget, in pixels. `0` leaves that dimension unconstrained.
+    ///
+    /// The widget will not grow beyond this size, even if it expands, and a dialog cannot be
+    /// resized beyond it by the user.
+    ///
+    /// The size is in logical pixels if `kiss_ui::set_dpi_aware(true)` was called.
+    fn set_max_size(self, width: u32, height: u32) -> Self {
+        // IUP's own default for no maximum.
+        fn unconstrained(dim: u32) -> u32 {
+            if dim == 0 { 65535 } else { ::dpi::to_physical(dim) }
+        }
+
+        let size = format!("{}x{}", unconstrained(width), unconstrained(height));
+        self.set_str_attribute(::attrs::MAX_SIZE, size);
+        self
+    }
+
+    /// Set the size of this widget in pixels. `0` lets that dimension be set by the natural
+    /// size of the widget.
+    ///
+    /// By default, sizes in pixels are physical pixels, which are not scaled on high-DPI
+    /// displays; see `kiss_ui::set_dpi_aware()` and `set_size()`.
+    fn set_size_pixels(self, width: u32, height: u32) -> Self {
+        let (width, height) = (::dpi::to_physical(width), ::dpi::to_physical(height));
+        self.set_opt_str_attribute(::attrs::RASTERSIZE, size_attribute(width, height));
+        self
+    }
+
+    /// Get the number of physical pixels per logical pixel on the screen showing this widget,
+    /// e.g. `1.5` at 144 DPI. See `kiss_ui::set_dpi_aware()`.
+    ///
+    /// IUP reports one resolution for all monitors, so this is the same for every widget.
+    fn scale_factor(&self) -> f32 {
+        ::dpi::scale_factor()
+    }
+
+    /// Set the size of this widget in dialog units, where `4` is the average width of a character
+    /// and `8` its height in the widget's font. `0` lets that dimension be set by the natural
+    /// size of the widget.
+    ///
+    /// Because they are relative to the font, sizes in dialog units scale with the display DPI
+    /// and the font size.
+    fn set_size(self, width: u32, height: u32) -> Self {
+        self.set_opt_str_attribute(::attrs::SIZE, size_attribute(width, height));
+        self
+    }
+
+    /// Set the font of this widget, in IUP's `"<face>, <styles> <size>"` format,
+    /// e.g. `"Times, Bold 12"`.
+    ///
+    /// Does nothing if the widget does not render text.
+    fn set_font(self, font: &str) -> Self {
+        self.set_str_attribute(::attrs::FONT, font);
+        self
+    }
+
+    /// Get the font of this widget, in the same format as `set_font()`.
+    fn get_font(&self) -> Option<String> {
+        self.get_str_attribute(::attrs::FONT).map(String::from)
+    }
+
+    /// Set the size of this widget's font in points, keeping its face and style.
+    fn set_font_size(self, pt: u32) -> Self {
+        self.set_int_attribute(::attrs::FONT_SIZE, pt as i32);
+        self
+    }
+
+    /// Set the style of this widget's font, keeping its face and size.
+    fn set_font_style(self, style: FontStyle) -> Self {
+        self.set_str_attribute(::attrs::FONT_STYLE, style.to_string());
+        self
+    }
+
+    /// Set the background color of this widget.
+    ///
+    /// Does nothing if the widget does not render a background.
+    fn set_bg_color(self, r: u8, g: u8, b: u8) -> Self {
+        self.set_str_attribute(::attrs::BGCOLOR, Color::new(r, g, b).to_string());
+        self
+    }
+
+    /// Get the background color of this widget.
+    ///
+    /// Returns `None` if no color is set or it is not in `"r g b"` format.
+    fn get_bg_color(&self) -> Option<(u8, u8, u8)> {
+        self.get_str_attribute(::attrs::BGCOLOR).and_then(Color::parse).map(Into::into)
+    }
+
+    /// Set the foreground (usually text) color of this widget.
+    ///
+    /// Does nothing if the widget does not render a foreground.
+    fn set_fg_color(self, r: u8, g: u8, b: u8) -> Self {
+        self.set_str_attribute(::attrs::FGCOLOR, Color::new(r, g, b).to_string());
+        self
+    }
+
+    /// Get the foreground color of this widget.
+    ///
+    /// Returns `None` if no color is set or it is not in `"r g b"` format.
+    fn get_fg_color(&self) -> Option<(u8, u8, u8)> {
+        self.get_str_attribute(::attrs::FGCOLOR).and_then(Color::parse).map(Into::into)
+    }
+
+    /// Set the text shown in a tooltip when the mouse hovers over this widget.
+    /// Can contain newlines.
+    ///
+    /// Setting an empty string is the same as calling `clear_tooltip()`.
+    fn set_tooltip(self, text: &str) -> Self {
+        if text.is_empty() {
+            self.clear_tooltip()
+        } else {
+            self.set_str_attribute(::attrs::TIP, text);
+            self
+        }
+    }
+
+    /// Remove the tooltip from this widget, if one was set.
+    fn clear_tooltip(self) -> Self {
+        self.set_opt_str_attribute::<String>(::attrs::TIP, None);
+        self
+    }
+
+    /// Get the tooltip text of this widget.
+    ///
+    /// Returns `None` if no tooltip is set.
+    fn get_tooltip(&self) -> Option<String> {
+        self.get_str_attribute(::attrs::TIP)
+            .and_then(|tip| if tip.is_empty() { None } else { Some(tip.to_owned()) })
+    }
+
+    /// Set how long the tooltip stays visible, in milliseconds.
+    fn set_tooltip_delay(self, ms: u32) -> Self {
+        self.set_int_attribute(::attrs::TIP_DELAY, ms as i32);
+        self
+    }
+
+    /// Set the mouse cursor shown while the pointer is over this widget.
+    ///
+    /// This takes effect immediately, even if the pointer is already over the widget. Use
+    /// `Cursor::Arrow` to restore the default.
+    fn set_cursor(self, cursor: Cursor) -> Self {
+        self.set_const_str_attribute(::attrs::CURSOR, cursor.as_cstr());
+        self
+    }
+
+    /// Use `image` as the mouse cursor while the pointer is over this widget. See `set_cursor()`.
+    ///
+    /// The hotspot of the cursor is the top-left corner of the image. Most platforms limit the
+    /// size of cursors, typically to 32x32 pixels.
+    fn set_cursor_from_image(self, image: Image) -> Self {
+        self.set_attr_handle(::attrs::CURSOR, image);
+        self
+    }
+
+    /// Set a menu to popup at the cursor when this widget is right-clicked.
+    ///
+    /// The previous context menu of this widget, if any, is destroyed, and so is this menu when
+    /// the widget is destroyed; in both cases only once no other widget uses the menu as its
+    /// context or tray menu. Right-clicks are still reported to the `OnMouseButton` callback,
+    /// if any, before the menu is shown.
+    fn set_context_menu(self, menu: Menu) -> Self {
+        ::menu::set_context_menu(self, menu);
+        self
+    }
+
+    /// Let the user drag data out of this widget, offering it as any of `types`, which are
+    /// arbitrary names such as `"TEXT"` or `"LIST_ITEM"`.
+    ///
+    /// The data is provided by the callback set with `set_on_drag_data()`. This must be set
+    /// before the widget is shown.
+    fn enable_drag_source(self, types: &[&str]) -> Self {
+        self.set_bool_attribute(::attrs::DRAG_SOURCE, true);
+        self.set_str_attribute(::attrs::DRAG_TYPES, types.join(","));
+        self
+    }
+
+    /// Let the user drop data onto this widget, if it is offered as any of `types`. A drop is
+    /// only accepted if the source and this widget have at least one type in common.
+    ///
+    /// The data is passed to the callback set with `set_on_drop_data()`. This must be set before
+    /// the widget is shown.
+    fn enable_drop_target(self, types: &[&str]) -> Self {
+        self.set_bool_attribute(::attrs::DROP_TARGET, true);
+        self.set_str_attribute(::attrs::DROP_TYPES, types.join(","));
+        self
+    }
+
+    /// Set a callback to be invoked when the user starts dragging data out of this widget, with
+    /// the position of the cursor relative to the widget's top-left corner.
+    ///
+    /// Return `true` to let the drag go ahead, e.g. after preparing the data, or `false` to
+    /// prevent it. See `enable_drag_source()`.
+    fn set_on_drag_begin<F>(self, on_drag_begin: F) -> Self
+    where F: FnMut((Self, i32, i32)) -> bool + 'static {
+        ::dragdrop::set_on_drag_begin(self, on_drag_begin);
+        self
+    }
+
+    /// Set a callback to be invoked when data is dragged out of this widget onto a drop target,
+    /// with the type the target accepted, returning the data to drop.
+    ///
+    /// See `enable_drag_source()`.
+    fn set_on_drag_data<F>(self, on_drag_data: F) -> Self
+    where F: FnMut((Self, String)) -> Vec<u8> + 'static {
+        ::dragdrop::set_on_drag_data(self, on_drag_data);
+        self
+    }
+
+    /// Set a callback to be invoked when data is dropped onto this widget, with its type, the
+    /// data itself, and the position of the drop relative to the widget's top-left corner.
+    ///
+    /// See `enable_drop_target()`.
+    fn set_on_drop_data<Cb>(self, on_drop_data: Cb) -> Self
+    where Cb: Callback<(Self, String, Vec<u8>, i32, i32)> {
+        ::dragdrop::set_on_drop_data(self, on_drop_data);
+        self
+    }
+
+    /// Set the raw IUP attribute `name` to `value`, for functionality that has no typed wrapper.
+    ///
+    /// Typed methods read the same attributes, so e.g. setting `"FONT"` here is reflected by
+    /// `get_font()`. IUP attribute names are case-sensitive, and all standard ones are uppercase.
+    /// See the IUP documentation for the attributes each widget supports.
+    ///
+    /// ##Panics
+    /// If `name` is not a valid attribute name (see `get_attribute()`), or `value` contains a
+    /// null byte.
+    fn set_attribute(&self, name: &str, value: &str) {
+        let c_name = raw_attribute_name(name);
+        let c_value = CString::new(value)
+            .unwrap_or_else(|_| panic!("value of attribute {:?} contains a null byte", name));
+
+        unsafe { iup_sys::IupSetStrAttribute(self.ptr(), c_name.as_ptr(), c_value.as_ptr()); }
+    }
+
+    /// Get the value of the raw IUP attribute `name`, or `None` if it is not set.
+    ///
+    /// Attributes that hold native handles rather than text, like `"WID"` or names starting with
+    /// `"_IUP"`, always return `None`. Invalid UTF-8 in the value is replaced.
+    ///
+    /// ##Panics
+    /// If `name` is empty or contains anything other than ASCII letters, digits, `_`, `:` and `*`,
+    /// which make up all IUP attribute names, including indexed ones like `"1:2"`.
+    fn get_attribute(&self, name: &str) -> Option<String> {
+        let c_name = raw_attribute_name(name);
+
+        if is_handle_attribute(name) {
+            return None;
+        }
+
+        unsafe {
+            let ptr = iup_sys::IupGetAttribute(self.ptr(), c_name.as_ptr());
+
+            if ptr.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+            }
+        }
     }
 
     /// Store this widget under `name`, returning the previous widget stored, if any.
     ///
     /// It may later be retrieved from any valid KISS-UI context 
     /// by calling `BaseWidget::load(name)`.
+    ///
+    /// Entries are kept until `show_gui()` returns; use `BaseWidget::remove_stored(name)` to
+    /// evict one sooner.
     fn store<N: Into<String>>(self, name: N) -> Option<BaseWidget> {
         ::WIDGET_STORE.with(|store| {
             store.borrow_mut().insert(name.into(), self.to_base())
@@ -140,12 +540,278 @@ pub trait Widget: IUPWidget {
 #[doc(hidden)]
 impl<T: IUPWidget> Widget for T {}
 
+/// An RGB color.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub fn new(r: u8, g: u8, b: u8) -> Color {
+        Color { r, g, b }
+    }
+
+    /// Parse a color in IUP's `"r g b"` format.
+    ///
+    /// Returns `None` if `val` is in any other format, such as a named color.
+    pub fn parse(val: &str) -> Option<Color> {
+        let mut channels = val.split_whitespace().map(str::parse::<u8>);
+
+        match (channels.next(), channels.next(), channels.next(), channels.next()) {
+            (Some(Ok(r)), Some(Ok(g)), Some(Ok(b)), None) => Some(Color::new(r, g, b)),
+            _ => None,
+        }
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((r, g, b): (u8, u8, u8)) -> Color {
+        Color::new(r, g, b)
+    }
+}
+
+impl From<Color> for (u8, u8, u8) {
+    fn from(color: Color) -> (u8, u8, u8) {
+        (color.r, color.g, color.b)
+    }
+}
+
+impl fmt::Display for Color {
+    /// Formats the color as IUP expects it, e.g. `"255 128 0"`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.r, self.g, self.b)
+    }
+}
+
+/// The style of a widget's font. Styles can be combined with `|`.
+///
+/// The default is a plain font.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub struct FontStyle {
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub strikeout: bool,
+}
+
+impl FontStyle {
+    pub const BOLD: FontStyle = FontStyle { bold: true, italic: false, underline: false, strikeout: false };
+    pub const ITALIC: FontStyle = FontStyle { bold: false, italic: true, underline: false, strikeout: false };
+    pub const UNDERLINE: FontStyle = FontStyle { bold: false, italic: false, underline: true, strikeout: false };
+    pub const STRIKEOUT: FontStyle = FontStyle { bold: false, italic: false, underline: false, strikeout: true };
+}
+
+impl BitOr for FontStyle {
+    type Output = FontStyle;
+
+    fn bitor(self, rhs: FontStyle) -> FontStyle {
+        FontStyle {
+            bold: self.bold || rhs.bold,
+            italic: self.italic || rhs.italic,
+            underline: self.underline || rhs.underline,
+            strikeout: self.strikeout || rhs.strikeout,
+        }
+    }
+}
+
+impl fmt::Display for FontStyle {
+    /// Formats the style as IUP expects it, e.g. `"Bold Italic"`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let styles = [
+            (self.bold, "Bold"),
+            (self.italic, "Italic"),
+            (self.underline, "Underline"),
+            (self.strikeout, "Strikeout"),
+        ];
+
+        let styles: Vec<_> = styles.iter().filter(|&&(set, _)| set).map(|&(_, name)| name).collect();
+        f.write_str(&styles.join(" "))
+    }
+}
+
+/// Check if `widget` has a native handle and belongs to a dialog, so its layout can be computed.
+fn is_mapped<W: Widget>(widget: &W) -> bool {
+    widget.get_str_attribute(::attrs::WID).is_some() && widget.get_dialog().is_some()
+}
+
+/// Validate `name` for `Widget::set_attribute()` and `Widget::get_attribute()`.
+fn raw_attribute_name(name: &str) -> CString {
+    let valid = !name.is_empty()
+        && name.bytes().all(|b| b.is_ascii_alphanumeric() || b"_:*".contains(&b));
+
+    assert!(valid, "{:?} is not a valid IUP attribute name", name);
+
+    CString::new(name).unwrap()
+}
+
+/// Check if IUP stores a pointer rather than a string under the attribute `name`.
+fn is_handle_attribute(name: &str) -> bool {
+    const HANDLE_ATTRS: &[&str] = &[
+        "WID", "HWND", "HFONT", "HDC_WMPAINT", "XWINDOW", "XDISPLAY", "XFONTSTRUCT", "XFONTID",
+        "DRAWABLE", "NATIVEPARENT", "PANGOFONTDESC", "PANGOLAYOUT", "CAIRO_CR", "GL_CONTEXT",
+    ];
+
+    name.starts_with("_IUP") || HANDLE_ATTRS.contains(&name)
+}
+
+/// Apply the native stacking order `z_order` to `widget` after it was moved within `parent`, if
+/// it is on-screen.
+fn restack<W>(widget: W, z_order: &'static str, parent: *mut iup_sys::Ihandle) -> W
+where W: Widget {
+    if widget.get_str_attribute(::attrs::WID).is_some() {
+        widget.set_const_str_attribute(::attrs::Z_ORDER, z_order);
+
+        if let Some(parent) = unsafe { BaseWidget::from_ptr_opt(parent) } {
+            parent.refresh();
+        }
+    }
+
+    widget
+}
+
+/// Check that `pred` holds for the widget at `ptr` and each of its parents.
+fn all_ancestors<F>(ptr: *mut iup_sys::Ihandle, mut pred: F) -> bool
+where F: FnMut(BaseWidget) -> bool {
+    let mut widget = unsafe { BaseWidget::from_ptr_opt(ptr) };
+
+    while let Some(current) = widget {
+        if !pred(current) {
+            return false;
+        }
+
+        widget = unsafe { BaseWidget::from_ptr_opt(iup_sys::IupGetParent(current.ptr())) };
+    }
+
+    true
+}
+
+/// Format a size for `SIZE` or `RASTERSIZE`, where an omitted dimension is natural.
+fn size_attribute(width: u32, height: u32) -> Option<String> {
+    match (width, height) {
+        (0, 0) => None,
+        (width, 0) => Some(format!("{}x", width)),
+        (0, height) => Some(format!("x{}", height)),
+        (width, height) => Some(format!("{}x{}", width, height)),
+    }
+}
+
+/// The directions in which a widget grows to fill the free space in its container.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Expand {
+    /// Grow in both directions.
+    Yes,
+    Horizontal,
+    Vertical,
+    /// Keep the natural size of the widget.
+    No,
+}
+
+impl Expand {
+    fn as_cstr(self) -> &'static str {
+        use self::Expand::*;
+        use attrs::values;
+
+        match self {
+            Yes => values::YES,
+            Horizontal => values::HORIZONTAL,
+            Vertical => values::VERTICAL,
+            No => values::NO,
+        }
+    }
+
+    fn from_str(val: &str) -> Expand {
+        use self::Expand::*;
+
+        // IUP also has "free" variants that don't propagate to the parent container.
+        match val {
+            "YES" => Yes,
+            "HORIZONTAL" | "HORIZONTALFREE" => Horizontal,
+            "VERTICAL" | "VERTICALFREE" => Vertical,
+            _ => No,
+        }
+    }
+}
+
+/// A trait describing a widget that shows a title, such as the text of a button or the title
+/// bar of a dialog.
+pub trait Titled: Widget {
+    /// Set the title of this widget. Can be blank.
+    fn set_title<T: Into<String>>(self, title: T) -> Self {
+        self.set_str_attribute(::attrs::TITLE, title);
+        self
+    }
+
+    /// Get the title of this widget.
+    ///
+    /// Returns `None` if the title was not set or is blank.
+    fn get_title(&self) -> Option<String> {
+        self.get_str_attribute(::attrs::TITLE)
+            .filter(|title| !title.is_empty())
+            .map(Into::into)
+    }
+}
+
 pub trait Destroy: Widget {
     fn destroy(self) {
         unsafe { iup_sys::IupDestroy(self.ptr()); }
     }
 }
 
+/// A guard that destroys the wrapped widget when dropped, e.g. to free an `Image` at the end of
+/// a scope:
+///
+/// ```ignore
+/// let icon = Owned::new(Image::from_file("icon.png")?);
+/// label.set_image(*icon);
+/// ```
+///
+/// The guard dereferences to the widget, so all its methods can be called through it. Copies of
+/// the widget taken out of the guard are destroyed along with it. Moving the guard moves the
+/// responsibility to destroy the widget; it is destroyed exactly once, by the last owner.
+///
+/// If the widget was already destroyed, e.g. along with the dialog containing it, or the guard
+/// outlives `kiss_ui::show_gui()`, dropping the guard does nothing.
+pub struct Owned<W: Destroy>(W, Rc<Cell<bool>>);
+
+impl<W: Destroy> Owned<W> {
+    /// Take ownership of `widget`, destroying it when this guard is dropped.
+    pub fn new(widget: W) -> Owned<W> {
+        let alive = Rc::new(Cell::new(true));
+        let hook_alive = alive.clone();
+
+        ::callback::on_destroy(widget, move |_| hook_alive.set(false));
+
+        Owned(widget, alive)
+    }
+
+    /// Release the widget without destroying it.
+    pub fn into_inner(self) -> W {
+        let widget = self.0;
+        let alive = unsafe { ptr::read(&self.1) };
+        mem::forget(self);
+        drop(alive);
+        widget
+    }
+}
+
+impl<W: Destroy> Deref for Owned<W> {
+    type Target = W;
+
+    fn deref(&self) -> &W {
+        &self.0
+    }
+}
+
+impl<W: Destroy> Drop for Owned<W> {
+    fn drop(&mut self) {
+        if ::kiss_running() && self.1.get() {
+            self.0.destroy();
+        }
+    }
+}
+
 #[doc(hidden)]
 pub trait IUPWidget: Copy {
     unsafe fn from_ptr(ptr: *mut iup_sys::Ihandle) -> Self;
@@ -162,6 +828,19 @@ pub trait IUPWidget: Copy {
 
     fn target_classname() -> &'static str;
 
+    /// Check if `classname` is the IUP class of this widget type, or one of its aliases.
+    fn is_target_class(classname: &[u8]) -> bool {
+        classname == Self::target_classname().as_bytes()
+    }
+
+    /// Check if `widget` can be used as this widget type.
+    ///
+    /// By default, only its class is checked; types that share an IUP class with others can
+    /// also check its attributes.
+    fn is_target_widget(widget: &BaseWidget) -> bool {
+        Self::is_target_class(widget.classname().to_bytes())
+    }
+
     fn classname(&self) -> &CStr {
         unsafe { CStr::from_ptr(iup_sys::IupGetClassName(self.ptr())) } 
     }
@@ -221,6 +900,49 @@ pub trait IUPWidget: Copy {
         (left, right)
     }
 
+    fn set_str_attribute_id<V>(self, name: &'static str, id: i32, val: V) where V: Into<String> {
+        let c_val = CString::new(val.into()).unwrap();
+        unsafe { iup_sys::IupSetStrAttributeId(self.ptr(), name.as_cstr(), id, c_val.as_ptr()); }
+    }
+
+    fn get_str_attribute_id(&self, name: &'static str, id: i32) -> Option<&str> {
+        let ptr = unsafe { iup_sys::IupGetAttributeId(self.ptr(), name.as_cstr(), id) };
+
+        if !ptr.is_null() {
+            unsafe {
+                let c_str = CStr::from_ptr(ptr);
+                Some(::std::str::from_utf8_unchecked(c_str.to_bytes()))
+            }
+        } else {
+            None
+        }
+    }
+
+    fn set_str_attribute_id2<V>(self, name: &'static str, lin: i32, col: i32, val: V)
+    where V: Into<String> {
+        let c_val = CString::new(val.into()).unwrap();
+        unsafe {
+            iup_sys::IupSetStrAttributeId2(self.ptr(), name.as_cstr(), lin, col, c_val.as_ptr());
+        }
+    }
+
+    fn get_str_attribute_id2(&self, name: &'static str, lin: i32, col: i32) -> Option<&str> {
+        let ptr = unsafe { iup_sys::IupGetAttributeId2(self.ptr(), name.as_cstr(), lin, col) };
+
+        if !ptr.is_null() {
+            unsafe {
+                let c_str = CStr::from_ptr(ptr);
+                Some(::std::str::from_utf8_unchecked(c_str.to_bytes()))
+            }
+        } else {
+            None
+        }
+    }
+
+    fn get_int_attribute_id(self, name: &'static str, id: i32) -> i32 {
+        unsafe { iup_sys::IupGetIntId(self.ptr(), name.as_cstr(), id) }
+    }
+
     fn set_float_attribute(self, name: &'static str, val: f32) {
         unsafe { iup_sys::IupSetFloat(self.ptr(), name.as_cstr(), val); } 
     }
@@ -229,11 +951,24 @@ pub trait IUPWidget: Copy {
         unsafe { iup_sys::IupGetFloat(self.ptr(), name.as_cstr()) }
     }
 
+    fn set_double_attribute(self, name: &'static str, val: f64) {
+        unsafe { iup_sys::IupSetDouble(self.ptr(), name.as_cstr(), val); }
+    }
+
+    fn get_double_attribute(self, name: &'static str) -> f64 {
+        unsafe { iup_sys::IupGetDouble(self.ptr(), name.as_cstr()) }
+    }
+
     fn set_bool_attribute(self, name: &'static str, val: bool) {
         let val = ::attrs::values::bool_yes_no(val);
         self.set_const_str_attribute(name, val);        
     }
 
+    fn get_bool_attribute(self, name: &'static str) -> bool {
+        // `IupGetInt()` returns 1 for "YES", "ON" and "TRUE".
+        self.get_int_attribute(name) != 0
+    }
+
     fn set_attr_handle<W: Widget>(self, name: &'static str, handle: W) {
         unsafe { iup_sys::IupSetAttributeHandle(self.ptr(), name.as_cstr(), handle.ptr()); }
     }
@@ -262,11 +997,27 @@ impl<'a, T: IUPWidget> IUPWidget for &'a T {
     fn target_classname() -> &'static str {
         T::target_classname()
     }
+
+    fn is_target_class(classname: &[u8]) -> bool {
+        T::is_target_class(classname)
+    }
+
+    fn is_target_widget(widget: &BaseWidget) -> bool {
+        T::is_target_widget(widget)
+    }
 }
 
 #[macro_export]
 macro_rules! impl_widget {
-    ($ty:ident, $classname:expr) => {
+    // For types sharing an IUP class with others, `$check` tells them apart by the attributes of
+    // `$widget`, a `BaseWidget` already known to be of class `$classname`.
+    ($ty:ident, $classname:expr, |$widget:ident| $check:expr) => {
+        impl_widget! { @impl $ty, $classname; |$widget| $check }
+    };
+    ($ty:ident, $classname:expr $(, $alias:expr)*) => {
+        impl_widget! { @impl $ty, $classname $(, $alias)*; |_widget| true }
+    };
+    (@impl $ty:ident, $classname:expr $(, $alias:expr)*; |$widget:ident| $check:expr) => {
         impl ::widget::IUPWidget for $ty {
             unsafe fn from_ptr(ptr: ::widget_prelude::IUPPtr) -> Self {
                 assert!(
@@ -286,7 +1037,16 @@ macro_rules! impl_widget {
 
             fn target_classname() -> &'static str {
                 $classname
-            }            
+            }
+
+            fn is_target_class(classname: &[u8]) -> bool {
+                classname == $classname.as_bytes() $(|| classname == $alias.as_bytes())*
+            }
+
+            fn is_target_widget(widget: &::base::BaseWidget) -> bool {
+                let $widget = *widget;
+                Self::is_target_class(::widget::IUPWidget::classname(widget).to_bytes()) && $check
+            }
         }
     
         impl Copy for $ty {}